@@ -0,0 +1,207 @@
+use aoc_2025_lib::{Answer, Part};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the answers manifest, stored next to the inputs folder.
+const MANIFEST_NAME: &str = "answers.toml";
+
+/// Expected answers of every day, keyed by year, day and part. Stored as a TOML file with a
+/// table per day, nested in a table per year:
+///
+/// ```toml
+/// [2024.day1]
+/// part1 = 1834060
+/// part2 = 21607792
+/// ```
+///
+/// Strings are text answers. Integers too large for a TOML integer are stored as a string in
+/// an inline table, like `part1 = { integer = "18446744073709551615" }`.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerManifest {
+    days: BTreeMap<(u32, u32), [Option<Answer>; 2]>,
+}
+
+/// Error loading or saving an answers manifest.
+#[derive(Debug, PartialEq)]
+pub enum ManifestError {
+    /// The manifest could not be read or written.
+    Io(String),
+    /// The manifest is not a valid answers manifest.
+    Invalid(String),
+}
+
+/// Default location of the answers manifest for an inputs folder.
+pub fn default_manifest_path(inputs_dir: &Path) -> PathBuf {
+    inputs_dir
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(MANIFEST_NAME)
+}
+
+/// Key of the inline table holding an integer too large for a TOML integer.
+const LARGE_INTEGER_KEY: &str = "integer";
+
+/// Convert a TOML value into an answer. Integers too large for a TOML integer are stored
+/// as a string in an inline table, so they can not be mistaken for text answers.
+fn parse_answer(value: &toml::Value) -> Option<Answer> {
+    match value {
+        toml::Value::Integer(value) => Some(Answer::Integer(*value as i128)),
+        toml::Value::String(value) => Some(Answer::Text(value.clone())),
+        toml::Value::Table(table) if table.len() == 1 => {
+            let value = table.get(LARGE_INTEGER_KEY)?.as_str()?;
+            value.parse::<i128>().ok().map(Answer::Integer)
+        }
+        _ => None,
+    }
+}
+
+/// Convert an answer into a TOML value.
+fn format_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(value) => match i64::try_from(*value) {
+            Ok(value) => value.to_string(),
+            Err(_) => format!("{{ {LARGE_INTEGER_KEY} = \"{value}\" }}"),
+        },
+        Answer::Text(value) => toml::Value::String(value.clone()).to_string(),
+        Answer::NotImplemented => unreachable!("Unimplemented parts are not recorded"),
+    }
+}
+
+impl AnswerManifest {
+    /// Parse the contents of an answers manifest.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table = content.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let mut manifest = Self::default();
+
+        for (year_key, days) in &table {
+            let year = year_key
+                .parse::<u32>()
+                .map_err(|_| format!("invalid year {year_key:?}, expected a key like \"2024\""))?;
+            let days = days
+                .as_table()
+                .ok_or(format!("expected a table of days for {year_key}"))?;
+
+            for (key, value) in days {
+                let day = key
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse::<u32>().ok())
+                    .ok_or(format!("invalid day {year_key}.{key}, expected a key like \"day1\""))?;
+                let parts = value
+                    .as_table()
+                    .ok_or(format!("expected a table of answers for {year_key}.{key}"))?;
+
+                for part in Part::ALL {
+                    let part_key = format!("part{part}");
+                    if let Some(value) = parts.get(&part_key) {
+                        let answer = parse_answer(value)
+                            .ok_or(format!("invalid answer for {year_key}.{key}.{part_key}"))?;
+                        manifest.set(year, day, part, answer);
+                    }
+                }
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    /// Load an answers manifest. A missing file is treated as an empty manifest.
+    pub fn load(path: &Path) -> Result<Self, ManifestError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|err| ManifestError::Invalid(format!("{}: {err}", path.display()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(ManifestError::Io(format!("failed to read {}: {err}", path.display()))),
+        }
+    }
+
+    /// Save the manifest.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Format the manifest as TOML. Days are written in ascending order of year and day.
+    pub fn to_toml(&self) -> String {
+        let mut output = String::new();
+        for ((year, day), parts) in &self.days {
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            writeln!(output, "[{year}.day{day}]").unwrap();
+            for (part, answer) in Part::ALL.iter().zip(parts) {
+                if let Some(answer) = answer {
+                    writeln!(output, "part{part} = {}", format_answer(answer)).unwrap();
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Get the expected answer of a part of a day.
+    pub fn expected(&self, year: u32, day: u32, part: Part) -> Option<&Answer> {
+        self.days.get(&(year, day))?[part.number() as usize - 1].as_ref()
+    }
+
+    /// Set the expected answer of a part of a day. Unimplemented parts are not recorded.
+    pub fn set(&mut self, year: u32, day: u32, part: Part, answer: Answer) {
+        if answer.is_implemented() {
+            self.days.entry((year, day)).or_default()[part.number() as usize - 1] = Some(answer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut manifest = AnswerManifest::default();
+        manifest.set(2025, 1, Part::One, Answer::from(7_u64));
+        manifest.set(2024, 10, Part::One, Answer::from(5_u64));
+        manifest.set(2024, 2, Part::One, Answer::from(u64::MAX));
+        manifest.set(2024, 2, Part::Two, Answer::from("abc"));
+        manifest.set(2024, 3, Part::One, Answer::NotImplemented);
+        manifest.set(2024, 4, Part::One, Answer::from("42"));
+        manifest.set(2024, 4, Part::Two, Answer::from("0123"));
+
+        let content = manifest.to_toml();
+        assert_eq!(
+            content,
+            "[2024.day2]\npart1 = { integer = \"18446744073709551615\" }\npart2 = \"abc\"\n\n\
+             [2024.day4]\npart1 = \"42\"\npart2 = \"0123\"\n\n\
+             [2024.day10]\npart1 = 5\n\n[2025.day1]\npart1 = 7\n"
+        );
+        assert_eq!(AnswerManifest::parse(&content).unwrap(), manifest);
+        assert_eq!(manifest.expected(2024, 3, Part::One), None);
+        assert_eq!(manifest.expected(2024, 10, Part::Two), None);
+        assert_eq!(manifest.expected(2024, 1, Part::One), None);
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        assert!(AnswerManifest::parse("[2024.first]\npart1 = 1\n").is_err());
+        assert!(AnswerManifest::parse("[day1]\npart1 = 1\n").is_err());
+        assert!(AnswerManifest::parse("[2024.day1]\npart1 = { integer = \"a\" }\n").is_err());
+        assert!(AnswerManifest::parse("[2024.day1]\npart1 = { text = \"1\" }\n").is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("aoc_answers_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join(MANIFEST_NAME);
+        assert_eq!(AnswerManifest::load(&path), Ok(AnswerManifest::default()));
+        fs::write(&path, "[2024.day1\n").unwrap();
+        assert!(matches!(AnswerManifest::load(&path), Err(ManifestError::Invalid(_))));
+        fs::write(&path, "[2024.first]\npart1 = 1\n").unwrap();
+        assert!(matches!(AnswerManifest::load(&path), Err(ManifestError::Invalid(_))));
+        assert!(matches!(AnswerManifest::load(&dir), Err(ManifestError::Io(_))));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+use aoc_2025_lib::{Day, Part};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Options controlling how a day is benchmarked.
+pub struct BenchOptions {
+    /// Number of measured runs of each phase.
+    pub runs: usize,
+    /// Number of unmeasured runs of each phase before measuring.
+    pub warmup: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self { runs: 20, warmup: 3 }
+    }
+}
+
+/// Summary statistics of the measured runs of a phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics of a non empty list of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Statistics of a single phase of a day.
+pub struct PhaseResult {
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Run `f` for the warmup runs and then the measured runs. Returns the duration of each
+/// measured run.
+fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    (0..options.runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// The examples to benchmark each part of a day on: the first example with an expected answer
+/// for the part, since some puzzles use a different example for each part, or else the first
+/// example. Returns `None` if the day has no examples.
+pub fn example_inputs(day: &Day) -> Option<[String; 2]> {
+    let examples = day.solution.examples();
+    let first = examples.first()?;
+    Some(Part::ALL.map(|part| {
+        examples
+            .iter()
+            .find(|example| example.expected(part).is_some())
+            .unwrap_or(first)
+            .input
+            .to_string()
+    }))
+}
+
+/// Benchmark the parse, part one and part two phases of a day, and the variants of the
+/// parts if the day has any.
+///
+/// # Arguments
+///    day: The day to benchmark.
+///    inputs: The puzzle input of each part. The parse phase is measured on the input of
+///            part one.
+///    options: Number of warmup and measured runs.
+pub fn bench_day(
+    day: &Day,
+    inputs: &[String; 2],
+    options: &BenchOptions,
+) -> aoc_2025_lib::Result<Vec<PhaseResult>> {
+    // Parse once up front so that the parts are measured on a valid input and any
+    // error is reported before measuring.
+    let parsed_one = day.solution.parse(&inputs[0])?;
+    let parsed_two = if inputs[1] == inputs[0] {
+        None
+    } else {
+        Some(day.solution.parse(&inputs[1])?)
+    };
+    let parsed = [parsed_one.as_ref(), parsed_two.as_deref().unwrap_or(parsed_one.as_ref())];
+    day.solution.part_one(parsed[0])?;
+    day.solution.part_two(parsed[1])?;
+
+    let parse = measure(options, || day.solution.parse(&inputs[0]));
+    let part_one = measure(options, || day.solution.part_one(parsed[0]));
+    let part_two = measure(options, || day.solution.part_two(parsed[1]));
+
+    let mut results = vec![
+        PhaseResult { phase: "parse".to_string(), stats: Stats::from_samples(&parse) },
+        PhaseResult { phase: "part 1".to_string(), stats: Stats::from_samples(&part_one) },
+        PhaseResult { phase: "part 2".to_string(), stats: Stats::from_samples(&part_two) },
+    ];
+
+    for (variant, (name, part)) in day.solution.variants().into_iter().enumerate() {
+        let parsed = parsed[part.number() as usize - 1];
+        day.solution.solve_variant(parsed, variant)?;
+        let samples = measure(options, || day.solution.solve_variant(parsed, variant));
+        results.push(PhaseResult {
+            phase: format!("part {part} ({name})"),
+            stats: Stats::from_samples(&samples),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Format the results of a benchmark as a table.
+pub fn format_results(day: &Day, options: &BenchOptions, results: &[PhaseResult]) -> String {
+    let mut output = String::new();
+    let width = results.iter().map(|result| result.phase.len()).max().unwrap_or(0).max(8);
+    writeln!(
+        output,
+        "{} day {} ({} runs, {} warmup)",
+        day.year, day.number, options.runs, options.warmup
+    )
+    .unwrap();
+    writeln!(
+        output,
+        "{:<width$} {:>12} {:>12} {:>12} {:>12}",
+        "Phase", "Min", "Median", "Mean", "Std Dev"
+    )
+    .unwrap();
+    for result in results {
+        let stats = result.stats;
+        writeln!(
+            output,
+            "{:<width$} {:>12} {:>12} {:>12} {:>12}",
+            result.phase,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.std_dev),
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // Population standard deviation of 1, 2, 3 and 4 is sqrt(1.25).
+        assert!((stats.std_dev.as_secs_f64() * 1000.0 - 1.25_f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_example_inputs() {
+        // Day 3 has a separate example for each part.
+        let day = aoc_2025_lib::find_day(2024, 3).unwrap();
+        let examples = day.solution.examples();
+        let inputs = example_inputs(day).unwrap();
+        assert_eq!(inputs, [examples[0].input, examples[1].input]);
+
+        let options = BenchOptions { runs: 1, warmup: 0 };
+        let results = bench_day(day, &inputs, &options).unwrap();
+        assert_eq!(results[2].phase, "part 2");
+    }
+}
@@ -0,0 +1,209 @@
+use aoc_2025_lib::Part;
+use clap::{Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+
+/// Root folder of the workspace.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("App crate should be in the crates folder of the workspace")
+}
+
+/// Default inputs folder of the workspace.
+pub fn default_inputs_dir() -> PathBuf {
+    workspace_dir().join("inputs")
+}
+
+/// Default year of the commands that work on a single day.
+fn default_year() -> u32 {
+    aoc_2025_lib::latest_year().expect("At least one day should be solved")
+}
+
+/// Run, benchmark and verify the Advent of Code solutions.
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve a single day.
+    Run(RunArgs),
+    /// Solve every day and print a summary table.
+    All(AllArgs),
+    /// Benchmark the parse, part one and part two phases of a day.
+    Bench(BenchArgs),
+    /// Check the answers of every day against the answers manifest.
+    Verify(VerifyArgs),
+    /// Create a new day module from a template, along with an empty input file.
+    New(NewArgs),
+}
+
+/// Folder containing a `YYYY/dayN.txt` puzzle input for every year.
+#[derive(Debug, Args)]
+pub struct InputsDirArgs {
+    /// Folder containing a `YYYY` folder of `dayN.txt` puzzle inputs for every year.
+    #[arg(long, value_name = "DIR", default_value_os_t = default_inputs_dir())]
+    pub inputs_dir: PathBuf,
+}
+
+/// Where to read the puzzle input of a single day from.
+#[derive(Debug, Args)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file instead of the inputs folder. Use `-` to read
+    /// the puzzle input from standard input.
+    #[arg(long, value_name = "FILE", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub inputs_dir: InputsDirArgs,
+
+    /// Run against the examples from the puzzle description instead of the puzzle input.
+    #[arg(long)]
+    pub example: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct AllArgs {
+    /// Only solve the days of this year.
+    #[arg(long)]
+    pub year: Option<u32>,
+
+    #[command(flatten)]
+    pub inputs_dir: InputsDirArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// The year of the day. Defaults to the most recent year.
+    #[arg(long, default_value_t = default_year())]
+    pub year: u32,
+
+    /// The day to solve.
+    #[arg(long)]
+    pub day: u32,
+
+    /// Solve only this part of the day (1 or 2).
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    /// Print the day's report after the answers, describing how the puzzle was solved.
+    #[arg(long, conflicts_with = "example")]
+    pub report: bool,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// The year of the day. Defaults to the most recent year.
+    #[arg(long, default_value_t = default_year())]
+    pub year: u32,
+
+    /// The day to benchmark.
+    #[arg(long)]
+    pub day: u32,
+
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Number of measured runs of each phase.
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u64).range(1..))]
+    pub runs: u64,
+
+    /// Number of unmeasured runs of each phase before measuring.
+    #[arg(long, default_value_t = 3)]
+    pub warmup: u64,
+
+    /// Also save the results to this file.
+    #[arg(long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only verify the days of this year.
+    #[arg(long)]
+    pub year: Option<u32>,
+
+    #[command(flatten)]
+    pub inputs_dir: InputsDirArgs,
+
+    /// Answers manifest to verify against. Defaults to `answers.toml` next to the inputs folder.
+    #[arg(long, value_name = "FILE")]
+    pub answers: Option<PathBuf>,
+
+    /// Record the current answers as the new expected answers.
+    #[arg(long)]
+    pub record: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    /// The year of the day. Defaults to the most recent year.
+    #[arg(long, default_value_t = default_year())]
+    pub year: u32,
+
+    /// The day to create, from 1 to 25.
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    #[command(flatten)]
+    pub inputs_dir: InputsDirArgs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_new() {
+        let cli = Cli::try_parse_from(["aoc", "new", "--year", "2025", "25"]).unwrap();
+        let Command::New(args) = cli.command else {
+            panic!("Expected new command");
+        };
+        assert_eq!((args.year, args.day), (2025, 25));
+
+        assert!(Cli::try_parse_from(["aoc", "new", "0"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "new", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let cli = Cli::try_parse_from(["aoc", "run", "--day", "6", "--part", "2", "--example"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("Expected run command");
+        };
+        assert_eq!(args.year, default_year());
+        assert_eq!(args.day, 6);
+        assert_eq!(args.part, Some(Part::Two));
+        assert!(args.input.example);
+
+        let cli = Cli::try_parse_from(["aoc", "run", "--year", "2025", "--day", "1"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("Expected run command");
+        };
+        assert_eq!(args.year, 2025);
+        assert!(!args.report);
+
+        let cli = Cli::try_parse_from(["aoc", "run", "--day", "5", "--report"]).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("Expected run command");
+        };
+        assert!(args.report);
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "5", "--report", "--example"]).is_err());
+
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "x"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "1", "--part", "3"]).is_err());
+    }
+}
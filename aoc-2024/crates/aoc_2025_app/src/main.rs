@@ -1,52 +1,282 @@
-use std::env;
-use std::path::Path;
-use paste::paste;
-
-macro_rules! match_days {
-    ($curr_day:expr, $($day_num:tt),*) => {
-        match $curr_day {
-            $(
-                $day_num => {
-                    paste! {
-                        aoc_2025_lib::[<day $day_num>]::main
-                    }
-                },
-            )*
-            _ => {
-                println!("Invalid day: {}", $curr_day);
-                return;
-            }
+mod answers;
+mod bench;
+mod cli;
+mod runner;
+mod scaffold;
+mod verify;
+
+use answers::ManifestError;
+use aoc_2025_lib::{Answer, AocError, Day, Part};
+use bench::BenchOptions;
+use clap::Parser;
+use scaffold::ScaffoldError;
+use cli::{AllArgs, BenchArgs, Cli, Command, InputArgs, NewArgs, RunArgs, VerifyArgs};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Exit code when an answer does not match its expected answer.
+const EXIT_MISMATCH: u8 = 1;
+/// Exit code when the command line arguments are invalid. Matches the code used by clap.
+const EXIT_USAGE: u8 = 2;
+/// Exit code when a file cannot be read or written.
+const EXIT_IO: u8 = 3;
+/// Exit code when a day fails to parse its input or solve the puzzle.
+const EXIT_SOLVER: u8 = 4;
+/// Exit code when the answers manifest is not a valid answers manifest.
+const EXIT_MANIFEST: u8 = 5;
+
+/// Error returned by a command. Determines the exit code of the process.
+enum CommandError {
+    /// The arguments are valid for clap but not for the command.
+    Usage(String),
+    /// A file could not be read or written.
+    Io(String),
+    /// A day failed to parse its input or solve the puzzle.
+    Solver(String),
+    /// The answers manifest is not a valid answers manifest.
+    Manifest(String),
+    /// One or more answers did not match. The details have already been printed.
+    Mismatch,
+}
+
+impl CommandError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CommandError::Usage(_) => EXIT_USAGE,
+            CommandError::Io(_) => EXIT_IO,
+            CommandError::Solver(_) => EXIT_SOLVER,
+            CommandError::Manifest(_) => EXIT_MANIFEST,
+            CommandError::Mismatch => EXIT_MISMATCH,
+        }
+    }
+}
+
+impl From<AocError> for CommandError {
+    fn from(err: AocError) -> Self {
+        match err {
+            AocError::Io { .. } => CommandError::Io(err.to_string()),
+            _ => CommandError::Solver(err.to_string()),
         }
+    }
+}
+
+type CommandResult = Result<(), CommandError>;
+
+/// Look up a day in the registry.
+fn find_day(year: u32, day: u32) -> Result<&'static Day, CommandError> {
+    aoc_2025_lib::find_day(year, day)
+        .ok_or(CommandError::Usage(format!("day {day} of {year} is not solved")))
+}
+
+/// Where the puzzle input of a day is read from.
+enum InputSource {
+    /// A file, either given with `--input` or in the inputs folder.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Select the source of the puzzle input of a day from the input arguments.
+    fn new(day: &Day, args: &InputArgs) -> Self {
+        match &args.input {
+            Some(input) if input.as_os_str() == "-" => InputSource::Stdin,
+            Some(input) => InputSource::File(input.clone()),
+            None => InputSource::File(runner::input_path(
+                &args.inputs_dir.inputs_dir,
+                day.year,
+                day.number,
+            )),
+        }
+    }
+
+    /// Path used to report errors in the puzzle input.
+    fn path(&self) -> &Path {
+        match self {
+            InputSource::File(path) => path,
+            InputSource::Stdin => Path::new("<stdin>"),
+        }
+    }
+
+    /// Read the puzzle input.
+    fn read(&self) -> Result<String, CommandError> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+        };
+
+        result.map_err(|err| {
+            CommandError::Io(format!("failed to read {}: {err}", self.path().display()))
+        })
+    }
+}
+
+/// Solve the selected part of a day from its puzzle input, or both parts if no part is
+/// selected.
+fn solve(day: &Day, input: &str, part: Option<Part>) -> aoc_2025_lib::Result<Vec<(Part, Answer)>> {
+    let parsed = day.solution.parse(input)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
     };
+
+    parts
+        .into_iter()
+        .map(|part| Ok((part, day.solution.solve_part(parsed.as_ref(), part)?)))
+        .collect()
+}
+
+/// Describe how a day solved its puzzle input.
+fn report(day: &Day, input: &str) -> aoc_2025_lib::Result<Option<String>> {
+    let parsed = day.solution.parse(input)?;
+    day.solution.report(parsed.as_ref())
 }
-fn main() {
-    let args = env::args().collect::<Vec<_>>();
-    
-    if args.len() < 3 {
-        println!("Error: Invalid arguments");
-        return;
+
+/// Run a day against the examples from its puzzle description instead of the real input.
+fn run_examples(day: &Day, part: Option<Part>) -> CommandResult {
+    if day.solution.examples().is_empty() {
+        return Err(CommandError::Usage(format!(
+            "day {} of {} has no examples",
+            day.number, day.year
+        )));
     }
 
-    let day_arg = &args[1];
-    let input_folder_arg = &args[2];
+    for (idx, example) in day.solution.examples().iter().enumerate() {
+        println!("Example {}:", idx + 1);
+        for (part, answer) in solve(day, example.input, part)? {
+            match example.expected(part) {
+                Some(expected) => println!("Part {part}: {answer} (expected {expected})"),
+                None => println!("Part {part}: {answer}"),
+            }
+        }
+    }
 
-    // Select main function to run.
-    let day = u32::from_str_radix(day_arg, 10).unwrap();
-    let _main = match_days!(day, 1, 2, 3, 4, 5, 6, 7, 8);
+    Ok(())
+}
+
+/// Solve a single day.
+fn run(args: RunArgs) -> CommandResult {
+    let day = find_day(args.year, args.day)?;
+    if args.input.example {
+        return run_examples(day, args.part);
+    }
+
+    let source = InputSource::new(day, &args.input);
+    let input = source.read()?;
+    let answers = solve(day, &input, args.part).map_err(|err| err.with_file(source.path()))?;
+    for (part, answer) in answers {
+        println!("Part {part}: {answer}");
+    }
+
+    if args.report {
+        match report(day, &input).map_err(|err| err.with_file(source.path()))? {
+            Some(report) => print!("\n{report}"),
+            None => println!("\nDay {} of {} has no report", day.number, day.year),
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the registered days of a year, or of every year, and print a summary table.
+fn run_all(args: AllArgs) -> CommandResult {
+    let reports = runner::days(args.year)
+        .map(|day| runner::run_day(day, &args.inputs_dir.inputs_dir))
+        .collect::<Vec<_>>();
+
+    runner::print_summary(&reports);
+
+    let failed = reports.iter().any(|report| {
+        matches!(report.status, runner::Status::Failed(_) | runner::Status::Panicked(_))
+    });
+    if failed {
+        return Err(CommandError::Solver("one or more days failed".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Benchmark a single day.
+fn run_bench(args: BenchArgs) -> CommandResult {
+    let day = find_day(args.year, args.day)?;
+    let options = BenchOptions {
+        runs: args.runs as usize,
+        warmup: args.warmup as usize,
+    };
+
+    let source = InputSource::new(day, &args.input);
+    let (inputs, path) = if args.input.example {
+        let inputs = bench::example_inputs(day).ok_or(CommandError::Usage(format!(
+            "day {} of {} has no examples",
+            day.number, day.year
+        )))?;
+        (inputs, Path::new("<example>"))
+    } else {
+        let input = source.read()?;
+        ([input.clone(), input], source.path())
+    };
 
-    // Check that input folder exists.
-    if !Path::new(input_folder_arg).exists() {
-        println!("Invalid input path: {input_folder_arg}");
-        return;
+    let results = bench::bench_day(day, &inputs, &options).map_err(|err| err.with_file(path))?;
+    let output = bench::format_results(day, &options, &results);
+    print!("{output}");
+
+    if let Some(output_file) = args.output {
+        fs::write(&output_file, output).map_err(|err| {
+            CommandError::Io(format!("failed to write {}: {err}", output_file.display()))
+        })?;
     }
 
-    let input_file = format!("{input_folder_arg}/day{day_arg}.txt");
-    // Check that input file exists.
-    if !Path::new(&input_file).exists() {
-        println!("Invalid input path: {input_folder_arg}");
-        return;
+    Ok(())
+}
+
+/// Verify the answers of every day against the answers manifest.
+fn run_verify(args: VerifyArgs) -> CommandResult {
+    let inputs_dir = args.inputs_dir.inputs_dir;
+    let manifest_path = args
+        .answers
+        .unwrap_or_else(|| answers::default_manifest_path(&inputs_dir));
+
+    match verify::verify(args.year, &inputs_dir, &manifest_path, args.record) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(CommandError::Mismatch),
+        Err(ManifestError::Io(message)) => Err(CommandError::Io(message)),
+        Err(ManifestError::Invalid(message)) => Err(CommandError::Manifest(message)),
     }
+}
 
-    // Run main function.
-    _main(input_file);
-}
\ No newline at end of file
+/// Create a new day.
+fn run_new(args: NewArgs) -> CommandResult {
+    scaffold::new_day(args.year, args.day, &scaffold::lib_src_dir(), &args.inputs_dir.inputs_dir)
+        .map_err(|err| match err {
+            ScaffoldError::Exists(message) => CommandError::Usage(message),
+            ScaffoldError::Io(message) => CommandError::Io(message),
+        })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::All(args) => run_all(args),
+        Command::Bench(args) => run_bench(args),
+        Command::Verify(args) => run_verify(args),
+        Command::New(args) => run_new(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            match &err {
+                CommandError::Usage(message)
+                | CommandError::Io(message)
+                | CommandError::Solver(message)
+                | CommandError::Manifest(message) => eprintln!("Error: {message}"),
+                CommandError::Mismatch => {}
+            }
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
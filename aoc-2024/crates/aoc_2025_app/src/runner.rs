@@ -0,0 +1,189 @@
+use aoc_2025_lib::{Answer, AocError, Day};
+use std::any::Any;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Outcome of running a single day.
+pub enum Status {
+    /// Both parts were solved.
+    Ok,
+    /// The input file for the day does not exist.
+    MissingInput(PathBuf),
+    /// The day returned an error.
+    Failed(AocError),
+    /// The day panicked. Holds the panic message.
+    Panicked(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::MissingInput(_) => write!(f, "missing input"),
+            Status::Failed(_) => write!(f, "error"),
+            Status::Panicked(_) => write!(f, "panicked"),
+        }
+    }
+}
+
+/// Result of running a single day.
+pub struct DayReport {
+    /// The year of the day that was run.
+    pub year: u32,
+    /// The day that was run.
+    pub day: u32,
+    /// Answer to part one, if the day was solved.
+    pub part_one: Option<Answer>,
+    /// Answer to part two, if the day was solved.
+    pub part_two: Option<Answer>,
+    /// Outcome of the run.
+    pub status: Status,
+    /// Time taken to parse the input and solve both parts.
+    pub elapsed: Duration,
+}
+
+/// Path of the input file of a day within the inputs folder. The inputs of each year are
+/// kept in their own `YYYY` folder.
+pub fn input_path(inputs_dir: &Path, year: u32, day: u32) -> PathBuf {
+    inputs_dir.join(year.to_string()).join(format!("day{day}.txt"))
+}
+
+/// Registered days of a year, or of every year if no year is given.
+pub fn days(year: Option<u32>) -> impl Iterator<Item = &'static Day> {
+    aoc_2025_lib::DAYS
+        .iter()
+        .filter(move |day| year.is_none_or(|year| day.year == year))
+}
+
+/// Read the input file and solve both parts of the day.
+pub fn solve(day: &Day, input_file: &Path) -> aoc_2025_lib::Result<(Answer, Answer)> {
+    let input = fs::read_to_string(input_file).map_err(|source| AocError::Io {
+        path: input_file.to_path_buf(),
+        source,
+    })?;
+
+    day.solve(&input).map_err(|err| err.with_file(input_file))
+}
+
+/// Extract the message from the payload of a panic.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Run a day against its input in the inputs folder. Errors and panics are captured in
+/// the status of the report.
+pub fn run_day(day: &Day, inputs_dir: &Path) -> DayReport {
+    let input_file = input_path(inputs_dir, day.year, day.number);
+    let mut report = DayReport {
+        year: day.year,
+        day: day.number,
+        part_one: None,
+        part_two: None,
+        status: Status::Ok,
+        elapsed: Duration::ZERO,
+    };
+
+    if !input_file.exists() {
+        report.status = Status::MissingInput(input_file);
+        return report;
+    }
+
+    // The panic message is reported in the status, so silence the default hook while the day
+    // runs to keep it from being printed in the middle of the results.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solve(day, &input_file)));
+    report.elapsed = start.elapsed();
+    panic::set_hook(hook);
+
+    match result {
+        Ok(Ok((part_one, part_two))) => {
+            report.part_one = Some(part_one);
+            report.part_two = Some(part_two);
+        }
+        Ok(Err(err)) => report.status = Status::Failed(err),
+        Err(payload) => report.status = Status::Panicked(panic_message(payload)),
+    }
+
+    report
+}
+
+/// Print a table with a header row. Columns are padded to the width of their widest cell.
+pub fn print_table<const N: usize>(headers: &[&str; N], rows: &[[String; N]]) {
+    let mut widths = headers.map(|header| header.len());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", format_row(&headers.map(String::from)));
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
+
+/// Print a summary table of the reports followed by the details of any failures.
+pub fn print_summary(reports: &[DayReport]) {
+    let headers = ["Year", "Day", "Part 1", "Part 2", "Status", "Time"];
+    let rows: Vec<[String; 6]> = reports
+        .iter()
+        .map(|report| {
+            let answer = |answer: &Option<Answer>| {
+                answer.as_ref().map_or("-".to_string(), |answer| answer.to_string())
+            };
+            [
+                report.year.to_string(),
+                report.day.to_string(),
+                answer(&report.part_one),
+                answer(&report.part_two),
+                report.status.to_string(),
+                match report.status {
+                    Status::MissingInput(_) => "-".to_string(),
+                    _ => format!("{:.2?}", report.elapsed),
+                },
+            ]
+        })
+        .collect();
+
+    print_table(&headers, &rows);
+    print_failures(reports);
+}
+
+/// Print the details of every report that was not solved.
+pub fn print_failures(reports: &[DayReport]) {
+    for report in reports {
+        match &report.status {
+            Status::Ok => {}
+            Status::MissingInput(path) => {
+                println!("{} day {}: missing input {}", report.year, report.day, path.display())
+            }
+            Status::Failed(err) => println!("{} day {}: {err}", report.year, report.day),
+            Status::Panicked(message) => {
+                println!("{} day {}: panicked: {message}", report.year, report.day)
+            }
+        }
+    }
+}
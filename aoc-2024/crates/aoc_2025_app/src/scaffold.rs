@@ -0,0 +1,84 @@
+use crate::cli::workspace_dir;
+use crate::runner;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Template of a new day module. `{day}` is replaced with the day number.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+/// Source folder of the library crate.
+pub fn lib_src_dir() -> PathBuf {
+    workspace_dir().join("crates/aoc_2025_lib/src")
+}
+
+/// Error creating a new day.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The module of the day already exists.
+    Exists(String),
+    /// A file or folder could not be created.
+    Io(String),
+}
+
+/// Create the module and placeholder input of a new day. The library discovers the module
+/// when it is built, so no further registration is needed.
+///
+/// # Arguments
+///    year: The year of the day.
+///    day: The day to create.
+///    lib_src_dir: Source folder of the library crate.
+///    inputs_dir: Folder of the puzzle inputs.
+pub fn new_day(
+    year: u32,
+    day: u32,
+    lib_src_dir: &Path,
+    inputs_dir: &Path,
+) -> Result<(), ScaffoldError> {
+    let year_dir = lib_src_dir.join(format!("year{year}"));
+    let module_path = year_dir.join(format!("day{day}.rs"));
+    if module_path.exists() {
+        return Err(ScaffoldError::Exists(format!("{} already exists", module_path.display())));
+    }
+
+    fs::create_dir_all(&year_dir).map_err(|err| {
+        ScaffoldError::Io(format!("failed to create {}: {err}", year_dir.display()))
+    })?;
+    let module = DAY_TEMPLATE.replace("{day}", &day.to_string());
+    fs::write(&module_path, module).map_err(|err| {
+        ScaffoldError::Io(format!("failed to write {}: {err}", module_path.display()))
+    })?;
+    println!("Created {}", module_path.display());
+
+    let input_path = runner::input_path(inputs_dir, year, day);
+    let year_inputs_dir = input_path.parent().unwrap_or(inputs_dir);
+    if !input_path.exists() {
+        fs::create_dir_all(year_inputs_dir).map_err(|err| {
+            ScaffoldError::Io(format!("failed to create {}: {err}", year_inputs_dir.display()))
+        })?;
+        fs::write(&input_path, "").map_err(|err| {
+            ScaffoldError::Io(format!("failed to write {}: {err}", input_path.display()))
+        })?;
+        println!("Created {}", input_path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let dir = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let (lib_src_dir, inputs_dir) = (dir.join("src"), dir.join("inputs"));
+
+        new_day(2025, 3, &lib_src_dir, &inputs_dir).unwrap();
+        assert!(lib_src_dir.join("year2025/day3.rs").exists());
+        assert!(inputs_dir.join("2025/day3.txt").exists());
+        let err = new_day(2025, 3, &lib_src_dir, &inputs_dir).unwrap_err();
+        assert!(matches!(err, ScaffoldError::Exists(_)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+use crate::answers::{AnswerManifest, ManifestError};
+use crate::runner::{self, DayReport, Status};
+use aoc_2025_lib::{Answer, Part};
+use std::fmt;
+use std::path::Path;
+
+/// Result of checking one part of a day against the answers manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer matches the expected answer.
+    Pass,
+    /// The answer does not match the expected answer, or the day failed to run.
+    Fail,
+    /// There is no expected answer, or no input to compute the answer from.
+    Missing,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail => write!(f, "FAIL"),
+            Outcome::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Compare the answer of a part with its expected answer.
+fn check(report: &DayReport, actual: &Option<Answer>, expected: Option<&Answer>) -> Outcome {
+    match (&report.status, actual, expected) {
+        (Status::MissingInput(_), _, _) => Outcome::Missing,
+        (Status::Failed(_) | Status::Panicked(_), _, _) => Outcome::Fail,
+        (_, _, None) => Outcome::Missing,
+        (_, Some(actual), Some(expected)) if actual == expected => Outcome::Pass,
+        _ => Outcome::Fail,
+    }
+}
+
+/// Run the registered days and compare the answers with the answers manifest. If `record`
+/// is set, the answers of every solved day are saved to the manifest as the new expected
+/// answers instead. Returns false if any part failed.
+///
+/// # Arguments
+///    year: Only verify the days of this year, or every year if not set.
+///    inputs_dir: The folder containing the puzzle inputs.
+///    manifest_path: The answers manifest.
+///    record: Whether to record the current answers as the expected answers.
+pub fn verify(
+    year: Option<u32>,
+    inputs_dir: &Path,
+    manifest_path: &Path,
+    record: bool,
+) -> Result<bool, ManifestError> {
+    let mut manifest = AnswerManifest::load(manifest_path)?;
+    let reports = runner::days(year)
+        .map(|day| runner::run_day(day, inputs_dir))
+        .collect::<Vec<_>>();
+
+    if record {
+        for report in reports.iter().filter(|report| matches!(report.status, Status::Ok)) {
+            for (part, answer) in [(Part::One, &report.part_one), (Part::Two, &report.part_two)] {
+                if let Some(answer) = answer {
+                    manifest.set(report.year, report.day, part, answer.clone());
+                }
+            }
+        }
+        manifest
+            .save(manifest_path)
+            .map_err(|err| {
+                ManifestError::Io(format!("failed to write {}: {err}", manifest_path.display()))
+            })?;
+        println!("Recorded answers to {}", manifest_path.display());
+    }
+
+    let headers = ["Year", "Day", "Part", "Expected", "Actual", "Result"];
+    let mut rows = Vec::new();
+    let mut passed = true;
+    for report in &reports {
+        for (part, actual) in [(Part::One, &report.part_one), (Part::Two, &report.part_two)] {
+            let expected = manifest.expected(report.year, report.day, part);
+            let outcome = check(report, actual, expected);
+            passed &= outcome != Outcome::Fail;
+
+            let cell = |answer: Option<&Answer>| answer.map_or("-".to_string(), Answer::to_string);
+            rows.push([
+                report.year.to_string(),
+                report.day.to_string(),
+                part.to_string(),
+                cell(expected),
+                cell(actual.as_ref()),
+                outcome.to_string(),
+            ]);
+        }
+    }
+
+    runner::print_table(&headers, &rows);
+    runner::print_failures(&reports);
+
+    Ok(passed)
+}
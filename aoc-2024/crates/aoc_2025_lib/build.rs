@@ -0,0 +1,50 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Parse the number out of a file or folder name such as `day5.rs` or `year2024`.
+fn parse_number(name: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?.strip_suffix(suffix)?.parse().ok()
+}
+
+/// List the numbers of the entries of a folder that match `prefix<number>suffix`, in
+/// ascending order.
+fn numbered_entries(dir: &Path, prefix: &str, suffix: &str) -> Vec<u32> {
+    let mut numbers = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("{} should be readable: {err}", dir.display()))
+        .filter_map(|entry| parse_number(entry.ok()?.file_name().to_str()?, prefix, suffix))
+        .collect::<Vec<_>>();
+    numbers.sort();
+    numbers
+}
+
+/// Discover the day modules in the `src/yearYYYY` folders and generate the `for_each_day!`
+/// macro, which passes every year and the numbers of its days to a callback macro. `lib.rs`
+/// uses it to declare and register the days, so adding a new `yearYYYY/dayN.rs` file is all
+/// that is needed to add a day.
+fn main() {
+    let src_dir = Path::new("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let years = numbered_entries(src_dir, "year", "")
+        .into_iter()
+        .map(|year| {
+            let days = numbered_entries(&src_dir.join(format!("year{year}")), "day", ".rs");
+            let days = days.iter().map(u32::to_string).collect::<Vec<_>>().join(", ");
+            format!("{year} => [{days}]")
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    let generated = format!(
+        "/// Pass every year and the number of each of its day modules to the callback macro.\n\
+         macro_rules! for_each_day {{\n    \
+             ($callback:ident) => {{\n        \
+                 $callback!({years});\n    \
+             }};\n\
+         }}\n"
+    );
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    fs::write(Path::new(&out_dir).join("days.rs"), generated)
+        .expect("Generated file should be writable");
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A numeric answer. Wide enough to hold any signed or unsigned 64 bit answer.
+    Integer(i128),
+    /// A textual answer.
+    Text(String),
+    /// The part has not been implemented.
+    NotImplemented,
+}
+
+impl Answer {
+    /// Returns true if the part has been implemented.
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::NotImplemented
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::NotImplemented.to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_comparison() {
+        assert_eq!(Answer::from(42_usize), Answer::from(42_i64));
+        assert_ne!(Answer::from(42_u64), Answer::from("42"));
+        assert!(!Answer::NotImplemented.is_implemented());
+        assert!(Answer::from(0_u32).is_implemented());
+    }
+}
@@ -1,15 +1,65 @@
-pub(crate) mod common;
+pub mod answer;
+pub mod solution;
+
+pub use aoc_common::error;
+pub use answer::Answer;
+pub use error::{AocError, Result};
+pub use solution::{Day, DynSolution, Example, Part, Solution, Variant};
 
 use paste::paste;
 
-macro_rules! declare_modules {
-    ($($mod_number:tt),*) => {
+macro_rules! declare_days {
+    ($($year:tt => [$($day_number:tt),*]);*) => {
         $(
             paste! {
-                pub mod [<day $mod_number>];
+                #[doc = concat!("Solutions to the Advent of Code ", stringify!($year), " puzzles.")]
+                pub mod [<year $year>] {
+                    $(
+                        pub mod [<day $day_number>];
+                    )*
+                }
             }
         )*
+
+        /// Registry of all solved days, in ascending order of year and day.
+        pub static DAYS: &[Day] = &[
+            $(
+                $(
+                    paste! {
+                        Day::new(
+                            $year,
+                            $day_number,
+                            &[<year $year>]::[<day $day_number>]::[<Day $day_number>],
+                        )
+                    },
+                )*
+            )*
+        ];
     };
 }
 
-declare_modules!(1, 2, 3, 4, 5, 6, 7, 8);
\ No newline at end of file
+// Generated by `build.rs` from the `yearYYYY/dayN.rs` files in this folder.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+for_each_day!(declare_days);
+
+/// Look up a day in the registry.
+///
+/// # Arguments
+///    year: The year of the event.
+///    number: The day of the puzzle.
+pub fn find_day(year: u32, number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.year == year && day.number == number)
+}
+
+/// Years with at least one solved day, in ascending order.
+pub fn years() -> Vec<u32> {
+    let mut years = DAYS.iter().map(|day| day.year).collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// The most recent year with a solved day.
+pub fn latest_year() -> Option<u32> {
+    DAYS.last().map(|day| day.year)
+}
@@ -0,0 +1,279 @@
+use crate::{Answer, Result};
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+
+/// A part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The number of the part.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {s:?}, expected 1 or 2")),
+        }
+    }
+}
+
+/// Trait implemented by every day's puzzle solution. Solving a puzzle is split into
+/// a parse step, which turns the raw puzzle input into `Self::Input`, and the two parts
+/// of the puzzle which each compute an answer from the parsed input.
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts.
+    type Input: 'static;
+
+    /// Examples from the puzzle description with their expected answers.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Alternative ways of solving the parts, which are benchmarked alongside the parts and
+    /// must give the same answers.
+    const VARIANTS: &'static [Variant<Self::Input>] = &[];
+
+    /// Parse the raw puzzle input.
+    ///
+    /// # Arguments
+    ///    input: The contents of the puzzle input file.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Compute the answer to part one of the puzzle.
+    fn part_one(input: &Self::Input) -> Result<Answer>;
+
+    /// Compute the answer to part two of the puzzle.
+    fn part_two(input: &Self::Input) -> Result<Answer>;
+
+    /// Describe how the puzzle was solved, for example how much work each strategy did.
+    /// Returns `None` for puzzles without a report.
+    fn report(_input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// An example from a puzzle description. Puzzles sometimes use a different example for each
+/// part, so the expected answer of either part is optional.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    /// The example puzzle input.
+    pub input: &'static str,
+    /// The expected answer to part one, formatted as it is displayed.
+    pub part_one: Option<&'static str>,
+    /// The expected answer to part two, formatted as it is displayed.
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    /// The expected answer to a part, if the example has one.
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+/// An alternative way of solving a part of a puzzle.
+pub struct Variant<I: 'static> {
+    /// Short name of the variant.
+    pub name: &'static str,
+    /// The part the variant solves.
+    pub part: Part,
+    /// Compute the answer to the part.
+    pub solve: fn(&I) -> Result<Answer>,
+}
+
+/// Type erased version of `Solution` so that solutions for different days can be stored
+/// in the same registry. This is implemented for every `Solution`.
+pub trait DynSolution: Sync {
+    /// Parse the raw puzzle input.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Compute the answer to part one from the output of `parse`.
+    fn part_one(&self, input: &dyn Any) -> Result<Answer>;
+
+    /// Compute the answer to part two from the output of `parse`.
+    fn part_two(&self, input: &dyn Any) -> Result<Answer>;
+
+    /// Compute the answer to a single part from the output of `parse`.
+    fn solve_part(&self, input: &dyn Any, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Describe how the puzzle was solved from the output of `parse`.
+    fn report(&self, input: &dyn Any) -> Result<Option<String>>;
+
+    /// Examples from the puzzle description.
+    fn examples(&self) -> &'static [Example];
+
+    /// The names and parts of the alternative ways of solving the parts.
+    fn variants(&self) -> Vec<(&'static str, Part)>;
+
+    /// Compute an answer with the variant at an index of `variants` from the output of `parse`.
+    fn solve_variant(&self, input: &dyn Any, variant: usize) -> Result<Answer>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer> {
+        S::part_one(downcast_input::<S>(input))
+    }
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer> {
+        S::part_two(downcast_input::<S>(input))
+    }
+
+    fn report(&self, input: &dyn Any) -> Result<Option<String>> {
+        S::report(downcast_input::<S>(input))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn variants(&self) -> Vec<(&'static str, Part)> {
+        S::VARIANTS.iter().map(|variant| (variant.name, variant.part)).collect()
+    }
+
+    fn solve_variant(&self, input: &dyn Any, variant: usize) -> Result<Answer> {
+        (S::VARIANTS[variant].solve)(downcast_input::<S>(input))
+    }
+}
+
+/// Recover the parsed input of a solution from its type erased form.
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("Input should be the output of the same solution's parse")
+}
+
+/// A registered day and its solution.
+pub struct Day {
+    /// The year of the event.
+    pub year: u32,
+    /// The day of the puzzle.
+    pub number: u32,
+    /// The solution of the puzzle.
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    /// Create a new registry entry.
+    ///
+    /// # Arguments
+    ///    year: The year of the event.
+    ///    number: The day of the puzzle.
+    ///    solution: The solution of the puzzle.
+    pub const fn new(year: u32, number: u32, solution: &'static dyn DynSolution) -> Self {
+        Self { year, number, solution }
+    }
+
+    /// Parse the input and solve both parts of the puzzle. Returns the answers to
+    /// part one and part two.
+    pub fn solve(&self, input: &str) -> Result<(Answer, Answer)> {
+        let parsed = self.solution.parse(input)?;
+        let part_one = self.solution.part_one(parsed.as_ref())?;
+        let part_two = self.solution.part_two(parsed.as_ref())?;
+
+        Ok((part_one, part_two))
+    }
+
+    /// Parse the input and solve a single part of the puzzle.
+    pub fn solve_part(&self, input: &str, part: Part) -> Result<Answer> {
+        let parsed = self.solution.parse(input)?;
+        self.solution.solve_part(parsed.as_ref(), part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DAYS;
+
+    #[test]
+    fn test_part() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_registry() {
+        assert!(crate::years().contains(&2024));
+        assert_eq!(crate::find_day(2024, 1).map(|day| day.number), Some(1));
+        assert!(crate::find_day(2023, 1).is_none());
+        let keys = DAYS.iter().map(|day| (day.year, day.number)).collect::<Vec<_>>();
+        assert!(keys.windows(2).all(|keys| keys[0] < keys[1]));
+    }
+
+    #[test]
+    fn test_examples() {
+        for day in DAYS {
+            for (idx, example) in day.solution.examples().iter().enumerate() {
+                let (part_one, part_two) = day.solve(example.input).unwrap();
+                let expected = [(1, example.part_one, part_one), (2, example.part_two, part_two)];
+                for (part, expected, actual) in expected {
+                    if let Some(expected) = expected {
+                        assert_eq!(
+                            actual.to_string(),
+                            expected,
+                            "{} day {} example {} part {part}",
+                            day.year,
+                            day.number,
+                            idx + 1
+                        );
+                    }
+                }
+
+                let parsed = day.solution.parse(example.input).unwrap();
+                for (variant, (name, part)) in day.solution.variants().into_iter().enumerate() {
+                    let expected = match part {
+                        Part::One => example.part_one,
+                        Part::Two => example.part_two,
+                    };
+                    if let Some(expected) = expected {
+                        let actual = day.solution.solve_variant(parsed.as_ref(), variant).unwrap();
+                        assert_eq!(
+                            actual.to_string(),
+                            expected,
+                            "{} day {} example {} part {part} variant {name}",
+                            day.year,
+                            day.number,
+                            idx + 1
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::{parse_token, quick_sort, SortAlgorithm};
+use crate::{Answer, Example, AocError, Result, Solution};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use regex::Regex;
+
+/// Solution for day 1.
+pub struct Day1;
+
+fn calculate_distance(list_one: &[i64], list_two: &[i64]) -> Result<i64> {
+    let mut distance: i64 = 0;
+
+    if list_one.len() != list_two.len() {
+        return Err(AocError::malformed(None, "both lists should have same length"));
+    }
+
+    for it in list_one.iter().zip(list_two.iter()) {
+        distance += i64::abs(it.0 - it.1);
+    }
+
+    Ok(distance)
+}
+
+fn calculate_similarity(list_one: &[i64], list_two: &[i64]) -> Result<i64> {
+    let mut similarity: i64 = 0;
+    let mut list_two_map: HashMap<i64, i64> = HashMap::new();
+
+    for num in list_two {
+        match list_two_map.get(num) {
+            Some(count) => list_two_map.insert(*num, count + 1),
+            None => list_two_map.insert(*num, 1),
+        };
+    }
+
+    for num in list_one {
+        if let Some(count) = list_two_map.get(num) {
+            similarity += *count * *num;
+        }
+    }
+
+    Ok(similarity)
+}
+
+fn read_input(input: &str) -> Result<(Vec<i64>, Vec<i64>)> {
+    let mut list_one = Vec::<i64>::new();
+    let mut list_two: Vec<i64> = Vec::<i64>::new();
+
+    let re = Regex::new(r"\s+").expect("Regex should be valid");
+    for (line_idx, line) in input.lines().enumerate() {
+        let numbers: Vec<&str> = re.split(line).collect::<Vec<&str>>();
+        if numbers.len() != 2 {
+            return Err(AocError::malformed(
+                Some(line_idx + 1),
+                format!("expected two numbers, found {}", numbers.len()),
+            ));
+        }
+
+        let num_one = parse_token::<i64>(line, line_idx, numbers[0])?;
+        let num_two = parse_token::<i64>(line, line_idx, numbers[1])?;
+
+        list_one.push(num_one);
+        list_two.push(num_two);
+    }
+
+    Ok((list_one, list_two))
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+impl Solution for Day1 {
+    type Input = (Vec<i64>, Vec<i64>);
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("11"),
+            part_two: Some("31"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        let (mut list_one, mut list_two) = input.clone();
+        quick_sort(&mut list_one, |a, b| a.cmp(b));
+        quick_sort(&mut list_two, |a, b| a.cmp(b));
+
+        Ok(calculate_distance(&list_one, &list_two)?.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        let (list_one, list_two) = input;
+
+        Ok(calculate_similarity(list_one, list_two)?.into())
+    }
+
+    fn report(input: &Self::Input) -> Result<Option<String>> {
+        // Work done by each sorting algorithm to sort both lists for part one.
+        let mut report = format!("{:<18} {:>12} {:>10}\n", "Algorithm", "Comparisons", "Swaps");
+        for algorithm in SortAlgorithm::ALL {
+            let (mut list_one, mut list_two) = input.clone();
+            let stats = algorithm.sort_instrumented(&mut list_one, |a, b| a.cmp(b))
+                + algorithm.sort_instrumented(&mut list_two, |a, b| a.cmp(b));
+            writeln!(
+                report,
+                "{:<18} {:>12} {:>10}",
+                algorithm.to_string(),
+                stats.comparisons,
+                stats.swaps
+            )
+            .expect("Writing to a string should not fail");
+        }
+
+        Ok(Some(report))
+    }
+}
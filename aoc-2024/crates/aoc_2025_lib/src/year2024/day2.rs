@@ -0,0 +1,121 @@
+use aoc_common::parse_token;
+use crate::{Answer, Example, Result, Solution};
+use regex::Regex;
+
+/// Solution for day 2.
+pub struct Day2;
+
+fn is_safe_magnitude(prev: i64, curr: i64) -> bool {
+    let diff = i64::abs(prev - curr);
+
+    (1..=3).contains(&diff)
+}
+
+fn is_safe_direction(prev: i64, curr: i64, increase_expected: bool) -> bool {
+    let increasing = curr > prev;
+    
+    increasing == increase_expected
+}
+
+fn is_report_safe(reports: &[i64]) -> bool {
+    let mut prev_num = reports[0];
+    let mut increasing = false;
+
+    for (idx, &curr_num) in reports.iter().enumerate().skip(1) {
+        if idx == 1 {
+            increasing = curr_num > prev_num;
+        }
+
+        if !is_safe_direction(prev_num, curr_num, increasing) {
+            return false;
+        }
+
+        if !is_safe_magnitude(prev_num, curr_num)  {
+            return false;
+        }
+
+        prev_num = curr_num
+    }
+
+    true
+}
+
+fn is_report_safe_ext(reports: &[i64]) -> bool {
+    if is_report_safe(reports) {
+        return true;
+    }
+
+    // If report is not safe, check if report can be made safe by
+    // removing a single element.
+    for idx in 0..reports.len() {
+        let mut reports_dampened = reports.to_vec();
+        reports_dampened.remove(idx);
+        if is_report_safe(&reports_dampened) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Count the safe reports. If the problem dampener is enabled, a report that can be made
+/// safe by removing a single level is also counted as safe.
+fn calculate_safety(reports: &[Vec<i64>], dampener: bool) -> Result<i64> {
+    let mut safe_count: i64 = 0;
+
+    for numbers in reports {
+        let is_safe = if dampener {
+            is_report_safe_ext(numbers)
+        } else {
+            is_report_safe(numbers)
+        };
+
+        if is_safe {
+            safe_count += 1;
+        }
+    }
+
+    Ok(safe_count)
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i64>>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("2"),
+            part_two: Some("4"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"\s+").expect("Regex should be valid");
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                re.split(line)
+                    .map(|x| parse_token::<i64>(line, line_idx, x))
+                    .collect::<Result<Vec<i64>>>()
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(calculate_safety(input, false)?.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(calculate_safety(input, true)?.into())
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::parse_token;
+use crate::{Answer, Example, Result, Solution};
+use regex::{Match, Regex};
+
+/// Solution for day 3.
+pub struct Day3;
+
+/// Parse a number captured from the memory. On failure the error reports the line and
+/// column of the capture.
+fn parse_capture(content: &str, capture: Match) -> Result<i64> {
+    let preceding = &content[..capture.start()];
+    let line_start = preceding.rfind('\n').map_or(0, |idx| idx + 1);
+    let line_idx = preceding.matches('\n').count();
+
+    parse_token(&content[line_start..], line_idx, capture.as_str())
+}
+
+fn mull_it_over(content: &str) -> Result<i64> {
+    let mut result: i64 = 0;
+
+    let re = Regex::new(r"mul\((?<num1>\d{1,3}),(?<num2>\d{1,3})\)").expect("Regex should be valid");
+    for caps in re.captures_iter(content) {
+        let num1 = parse_capture(content, caps.name("num1").expect("Capture should exist"))?;
+        let num2 = parse_capture(content, caps.name("num2").expect("Capture should exist"))?;
+        
+        result += num1 * num2;
+    }
+
+    Ok(result)
+}
+
+fn mull_it_over_ext(content: &str) -> Result<i64> {
+    let mut result: i64 = 0;
+    let mut skip: bool = false;
+
+    let re = Regex::new(r"(mul\((?<num1>\d{1,3}),(?<num2>\d{1,3})\))|(?<do>do\(\))|(?<dont>don't\(\))").expect("Regex should be valid");
+    for caps in re.captures_iter(content) {
+        if caps.name("do").is_some() {
+            skip = false;
+            continue;
+        }
+
+        if caps.name("dont").is_some() {
+            skip = true;
+            continue;
+        }
+
+        if skip {
+            continue;
+        }
+
+        let num1 = parse_capture(content, caps.name("num1").expect("Capture should exist"))?;
+        let num2 = parse_capture(content, caps.name("num2").expect("Capture should exist"))?;
+        
+        result += num1 * num2;
+    }
+
+    Ok(result)
+}
+
+/// Example from the puzzle description for part one.
+const EXAMPLE_ONE: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+/// Example from the puzzle description for part two.
+const EXAMPLE_TWO: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+impl Solution for Day3 {
+    type Input = String;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE_ONE,
+            part_one: Some("161"),
+            part_two: None,
+        },
+        Example {
+            input: EXAMPLE_TWO,
+            part_one: None,
+            part_two: Some("48"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(mull_it_over(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(mull_it_over_ext(input)?.into())
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_common::{Direction8, Grid, Position};
+use crate::{Answer, Example, Result, Solution};
+
+/// Solution for day 4.
+pub struct Day4;
+
+pub struct XmasSearch {
+    grid: Grid<char>,
+}
+
+impl XmasSearch {
+    fn new(grid: Grid<char>) -> Self {
+        XmasSearch { grid }
+    }
+
+    /// Count the occurrences of XMAS in a line of the grid, written forwards or backwards.
+    fn count_xmas_in_line<'a>(line: impl Iterator<Item = &'a char>) -> u64 {
+        let line = line.collect::<Vec<_>>();
+        line.windows(4)
+            .filter(|window| {
+                let word = window.iter().map(|cell| **cell).collect::<Vec<_>>();
+                word == ['X', 'M', 'A', 'S'] || word == ['S', 'A', 'M', 'X']
+            })
+            .count() as u64
+    }
+
+    /// Count the occurrences of XMAS in the grid. XMAS can be written horizontally,
+    /// vertically or diagonally, forwards or backwards.
+    fn compute_xmas_occurrence(&self) -> u64 {
+        let rows = self.grid.iter_rows().map(|row| Self::count_xmas_in_line(row.iter()));
+        let columns = self.grid.iter_columns().map(Self::count_xmas_in_line);
+        let diagonals = self.grid.iter_diagonals().map(Self::count_xmas_in_line);
+        let anti_diagonals = self.grid.iter_anti_diagonals().map(Self::count_xmas_in_line);
+
+        rows.chain(columns).chain(diagonals).chain(anti_diagonals).sum()
+    }
+
+    /// Check if the current cell is the center of a X-MAS.
+    fn is_x_mas_cell(&self, position: Position) -> bool {
+        // If cell value is not 'A', it cannot be an X-MAS cell
+        if self.grid[position] != 'A' {
+            return false;
+        }
+
+        // If cell is at the edge of the grid, there are not enough surrounding cells
+        // to form a X. 
+        let corner = |direction: Direction8| {
+            self.grid
+                .step(position, direction.offset())
+                .map(|corner| self.grid[corner])
+        };
+        let (
+            Some(top_left_char),
+            Some(top_right_char),
+            Some(bottom_left_char),
+            Some(bottom_right_char),
+        ) = (
+            corner(Direction8::UpLeft),
+            corner(Direction8::UpRight),
+            corner(Direction8::DownLeft),
+            corner(Direction8::DownRight),
+        )
+        else {
+            return false;
+        };
+
+        let right_diagonal_is_mas = (top_left_char == 'M' && bottom_right_char == 'S') 
+            || (top_left_char == 'S' && bottom_right_char == 'M');
+        let left_diagonal_is_mas = (top_right_char == 'M' && bottom_left_char == 'S')
+            || (top_right_char == 'S' && bottom_left_char == 'M');
+
+        right_diagonal_is_mas && left_diagonal_is_mas 
+    }
+
+    /// Compute number of x-mas occurrences i.e. parts in the grid
+    /// where two diagonal instances of MAS interserct. This center of the
+    /// X will be the letter 'A' and the edges 'M' or 'A'
+    fn compute_x_mas_occurrence(&self) -> u64 {
+        self.grid
+            .positions()
+            .filter(|position| self.is_x_mas_cell(*position))
+            .count() as u64
+    }
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+impl Solution for Day4 {
+    type Input = XmasSearch;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("18"),
+            part_two: Some("9"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input)?;
+
+        Ok(XmasSearch::new(grid))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(input.compute_xmas_occurrence().into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(input.compute_x_mas_occurrence().into())
+    }
+}
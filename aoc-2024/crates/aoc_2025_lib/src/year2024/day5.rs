@@ -0,0 +1,812 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
+use std::sync::OnceLock;
+use aoc_common::{parse_token, select_nth_by, topological_sort, Cycle, SortAlgorithm, SortStats};
+use crate::{Answer, Example, AocError, Part, Result, Solution, Variant};
+
+/// Solution for day 5.
+pub struct Day5;
+
+/// Rules between pages below this id are stored in the bit matrix of `PageOrdering`. The
+/// pages of the puzzle input are two digit numbers, so their rules always fit.
+pub const DENSE_PAGE_LIMIT: u32 = 256;
+
+/// Page ordering rules. A rule `a|b` requires page `a` to come before page `b` when both
+/// pages are in the same update.
+///
+/// Rules between pages below a limit are stored in a bit matrix, so checking a rule is a
+/// single bit lookup. Rules involving a larger page are stored in hash sets, so that a few
+/// large page numbers do not blow up the size of the matrix.
+#[derive(Debug, Clone)]
+pub struct PageOrdering {
+    /// Rules between pages below this id are stored in `dense`.
+    dense_limit: u32,
+    /// Number of words in a row of `dense`.
+    row_words: usize,
+    /// Bit matrix of the rules between pages below `dense_limit`. Bit `after` of row `before`
+    /// is set if there is a rule `before|after`. Empty until the first of these rules is added.
+    dense: Vec<u64>,
+    /// Map of the other rules. Key is a page and value is the set of pages that must come
+    /// after it.
+    sparse: HashMap<u32, HashSet<u32>>,
+}
+
+impl Default for PageOrdering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PageOrdering {
+    /// Create page ordering rules without any rules.
+    pub fn new() -> Self {
+        Self::with_dense_limit(DENSE_PAGE_LIMIT)
+    }
+
+    /// Create page ordering rules without any rules, storing the rules between pages below
+    /// `dense_limit` in the bit matrix. A limit of 0 stores every rule in hash sets.
+    pub fn with_dense_limit(dense_limit: u32) -> Self {
+        Self {
+            dense_limit,
+            row_words: (dense_limit as usize).div_ceil(64),
+            dense: Vec::new(),
+            sparse: HashMap::new(),
+        }
+    }
+
+    /// Parse page ordering rules from lines of the form `<page>|<page>`.
+    ///
+    /// # Arguments
+    ///    rules: The rule lines, starting on the first line of the puzzle input.
+    pub fn parse(rules: &str) -> Result<Self> {
+        let mut ordering = Self::new();
+        for (line_idx, rule) in rules.lines().enumerate() {
+            let Some((before, after)) = rule.split_once("|") else {
+                return Err(AocError::malformed(
+                    Some(line_idx + 1),
+                    "expected a rule of the form \"<page>|<page>\"",
+                ));
+            };
+            let before = parse_token::<u32>(rule, line_idx, before)?;
+            let after = parse_token::<u32>(rule, line_idx, after)?;
+            ordering.add_rule(before, after);
+        }
+
+        Ok(ordering)
+    }
+
+    /// Copy the rules into page ordering rules with a different limit of the bit matrix.
+    pub fn with_same_rules(&self, dense_limit: u32) -> Self {
+        let mut ordering = Self::with_dense_limit(dense_limit);
+        for (before, after) in self.rules() {
+            ordering.add_rule(before, after);
+        }
+
+        ordering
+    }
+
+    /// Iterate over every rule as `(before, after)` pairs, in no particular order.
+    pub fn rules(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        let dense = self.dense.iter().enumerate().flat_map(move |(word_idx, word)| {
+            let before = (word_idx / self.row_words) as u32;
+            let first_after = (word_idx % self.row_words * 64) as u32;
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| (before, first_after + bit))
+        });
+        let sparse = self
+            .sparse
+            .iter()
+            .flat_map(|(before, successors)| successors.iter().map(|after| (*before, *after)));
+
+        dense.chain(sparse)
+    }
+
+    /// Return the index of the word of `dense` holding the rule `before|after` and the mask of
+    /// its bit, or `None` if the rule is stored in `sparse`.
+    fn dense_bit(&self, before: u32, after: u32) -> Option<(usize, u64)> {
+        if before >= self.dense_limit || after >= self.dense_limit {
+            return None;
+        }
+
+        let word_idx = before as usize * self.row_words + after as usize / 64;
+        Some((word_idx, 1 << (after % 64)))
+    }
+
+    /// Add the rule `before|after`. Returns false if the rule was already present.
+    pub fn add_rule(&mut self, before: u32, after: u32) -> bool {
+        let Some((word_idx, mask)) = self.dense_bit(before, after) else {
+            return self.sparse.entry(before).or_default().insert(after);
+        };
+
+        if self.dense.is_empty() {
+            self.dense = vec![0; self.row_words * self.dense_limit as usize];
+        }
+        let added = self.dense[word_idx] & mask == 0;
+        self.dense[word_idx] |= mask;
+
+        added
+    }
+
+    /// Remove the rule `before|after`. Returns false if there was no such rule.
+    pub fn remove_rule(&mut self, before: u32, after: u32) -> bool {
+        if let Some((word_idx, mask)) = self.dense_bit(before, after) {
+            let Some(word) = self.dense.get_mut(word_idx) else {
+                return false;
+            };
+            let removed = *word & mask != 0;
+            *word &= !mask;
+            return removed;
+        }
+
+        let Some(successors) = self.sparse.get_mut(&before) else {
+            return false;
+        };
+        let removed = successors.remove(&after);
+        if successors.is_empty() {
+            self.sparse.remove(&before);
+        }
+
+        removed
+    }
+
+    /// Check if a rule requires page `a` to come before page `b`.
+    pub fn must_precede(&self, a: u32, b: u32) -> bool {
+        match self.dense_bit(a, b) {
+            Some((word_idx, mask)) => self.dense.get(word_idx).is_some_and(|word| word & mask != 0),
+            None => self.sparse.get(&a).is_some_and(|successors| successors.contains(&b)),
+        }
+    }
+
+    /// Compare two pages by the rules. Pages without a rule between them are equal. This is
+    /// not a total order, so sorting with it only gives the right order when every pair of
+    /// pages in an update has a rule.
+    pub fn compare(&self, a: u32, b: u32) -> Ordering {
+        if self.must_precede(a, b) {
+            Ordering::Less
+        } else if self.must_precede(b, a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Check if the pages of an update are in the order required by the rules.
+    pub fn is_valid(&self, pages: &[u32]) -> bool {
+        // Pages observed so far, as a row of the bit matrix so they can be checked against the
+        // row of a page a word at a time. Pages are also kept in a set if some rules are stored
+        // in hash sets, which may involve pages on either side of the limit.
+        let mut observed_dense = vec![0_u64; self.row_words];
+        let mut observed_pages: HashSet<u32> = HashSet::new();
+
+        for page in pages {
+            // If a page that must come after this one was already observed, the rules have
+            // been broken.
+            if *page < self.dense_limit && !self.dense.is_empty() {
+                let row_start = *page as usize * self.row_words;
+                let row = &self.dense[row_start..row_start + self.row_words];
+                if row.iter().zip(&observed_dense).any(|(rule, observed)| rule & observed != 0) {
+                    return false;
+                }
+            }
+            if let Some(successors) = self.sparse.get(page) {
+                if !observed_pages.is_disjoint(successors) {
+                    return false;
+                }
+            }
+
+            if *page < self.dense_limit {
+                observed_dense[*page as usize / 64] |= 1 << (page % 64);
+            }
+            if !self.sparse.is_empty() {
+                observed_pages.insert(*page);
+            }
+        }
+
+        true
+    }
+
+    /// Check if the rules put a page in the middle of an update: half of the other pages must
+    /// precede it and the other half must follow it. Such a page is in the middle of every
+    /// order of the update that satisfies the rules.
+    pub fn is_middle_page(&self, pages: &[u32], page: u32) -> bool {
+        let middle_idx = pages.len() / 2;
+        let before = pages.iter().filter(|other| self.must_precede(**other, page));
+        let after = pages.iter().filter(|other| self.must_precede(page, **other));
+
+        before.count() == middle_idx && after.count() == pages.len() - 1 - middle_idx
+    }
+
+    /// Put the pages of an update in an order that satisfies every rule between them. Returns
+    /// the cycle in the rules between the pages if they can not be ordered, leaving the pages
+    /// untouched.
+    pub fn fix(&self, pages: &mut [u32]) -> std::result::Result<(), Cycle<u32>> {
+        let order = topological_sort(pages, |a, b| self.must_precede(*a, *b))?;
+        pages.copy_from_slice(&order);
+
+        Ok(())
+    }
+
+    /// List the rules broken by an update. A rule `a|b` is broken if `b` comes before `a`.
+    pub fn violations(&self, pages: &[u32]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for (after_idx, after) in pages.iter().enumerate() {
+            for (before_idx, before) in pages.iter().enumerate().skip(after_idx + 1) {
+                if self.must_precede(*before, *after) {
+                    violations.push(Violation {
+                        before: *before,
+                        after: *after,
+                        before_idx,
+                        after_idx,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// Find cycles in the rules between the pages of an update. Each cycle found is broken by
+    /// ignoring the rule from its last page to its first page, until the remaining rules can be
+    /// ordered, so every page that can not be ordered is on at least one of the cycles.
+    pub fn cycles(&self, pages: &[u32]) -> Vec<Cycle<u32>> {
+        let mut cycles = Vec::new();
+        let mut ignored_rules: HashSet<(u32, u32)> = HashSet::new();
+        while let Err(cycle) = topological_sort(pages, |a, b| {
+            self.must_precede(*a, *b) && !ignored_rules.contains(&(*a, *b))
+        }) {
+            let first = cycle.nodes[0];
+            let last = cycle.nodes[cycle.nodes.len() - 1];
+            ignored_rules.insert((last, first));
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+}
+
+/// Ways of finding the middle page of an update that is not in the right order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiddlePageStrategy {
+    /// Fix the order of the whole update with a topological sort. Gives the right middle
+    /// page for any rules without cycles.
+    Sort,
+    /// Quickselect the middle page, comparing pages by the rules. Only partitions the pages
+    /// around the middle. Sorting with rules that are missing between some pages can select
+    /// the wrong page, so the selected page is checked like `Count` checks its pages.
+    Select,
+    /// Take the page that exactly half of the other pages of the update must precede and the
+    /// other half must follow. Needs a rule between the middle page and every other page.
+    Count,
+}
+
+/// Struct to represent the print queue. Holds the page ordering rules and the
+/// list of updates to be printed.
+pub struct PrintQueue {
+    /// The page ordering rules.
+    ordering: PageOrdering,
+    /// List of updates. Each update is a list of pages.
+    updates: Vec<Vec<u32>>,
+    /// Line of each update in the puzzle input, starting from 1.
+    update_lines: Vec<usize>,
+    /// The page ordering rules stored in hash sets only, built on first use.
+    hashed_ordering: OnceLock<PageOrdering>,
+}
+
+/// Return the middle page of an update.
+fn middle_page(pages: &[u32]) -> u64 {
+    pages[pages.len() / 2].into()
+}
+
+/// A rule `before|after` broken by an update, which has `after` before `before`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The page the rule requires to come first.
+    pub before: u32,
+    /// The page the rule requires to come second.
+    pub after: u32,
+    /// Index of `before` in the update.
+    pub before_idx: usize,
+    /// Index of `after` in the update.
+    pub after_idx: usize,
+}
+
+/// The rules broken by an update that is not in the right order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUpdate {
+    /// Line of the update in the puzzle input, starting from 1.
+    pub line: usize,
+    /// The pages of the update.
+    pub pages: Vec<u32>,
+    /// The broken rules, ordered by the index of their `after` page.
+    pub violations: Vec<Violation>,
+}
+
+/// Format the pages of an update as they appear in the puzzle input.
+fn join_pages(pages: &[u32]) -> String {
+    pages.iter().map(u32::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Diagnostics of the page ordering rules against the updates.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Diagnostics {
+    /// Every update that is not in the right order.
+    pub invalid_updates: Vec<InvalidUpdate>,
+    /// Cycles in the rules between pages that appear together in an update. Updates with
+    /// pages on a cycle can not be put in the right order.
+    pub cycles: Vec<Cycle<u32>>,
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.invalid_updates.is_empty() {
+            writeln!(f, "Every update is in the right order")?;
+        }
+        for update in &self.invalid_updates {
+            writeln!(f, "Update on line {}: {}", update.line, join_pages(&update.pages))?;
+            for violation in &update.violations {
+                writeln!(
+                    f,
+                    "  rule {}|{} is broken: {} is page {} but {} is page {}",
+                    violation.before,
+                    violation.after,
+                    violation.before,
+                    violation.before_idx + 1,
+                    violation.after,
+                    violation.after_idx + 1
+                )?;
+            }
+        }
+
+        if self.cycles.is_empty() {
+            writeln!(f, "No cycles in the rules between pages of the same update")
+        } else {
+            writeln!(f, "Cycles in the rules between pages of the same update:")?;
+            for cycle in &self.cycles {
+                writeln!(f, "  {cycle}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl PrintQueue {
+    /// The page ordering rules with every rule stored in hash sets instead of the bit matrix,
+    /// to benchmark the two against each other.
+    fn hashed_ordering(&self) -> &PageOrdering {
+        self.hashed_ordering.get_or_init(|| self.ordering.with_same_rules(0))
+    }
+
+    /// Sum up the middle pages of the updates that are already in the right order.
+    fn middle_page_sum_valid(&self, ordering: &PageOrdering) -> u64 {
+        self.updates
+            .iter()
+            .filter(|pages| ordering.is_valid(pages))
+            .map(|pages| middle_page(pages))
+            .sum()
+    }
+
+    /// Find the middle page of an update that is not in the right order.
+    ///
+    /// # Arguments
+    ///    ordering: The page ordering rules.
+    ///    pages: The pages of the update.
+    ///    strategy: How to find the middle page.
+    fn fixed_middle_page(
+        ordering: &PageOrdering,
+        pages: &[u32],
+        strategy: MiddlePageStrategy,
+    ) -> Result<u64> {
+        let mut pages = pages.to_vec();
+        let middle_idx = pages.len() / 2;
+        match strategy {
+            MiddlePageStrategy::Sort => {
+                ordering.fix(&mut pages).map_err(|cycle| {
+                    AocError::Unsolvable(format!(
+                        "update {} can not be ordered, its rules form the cycle {cycle} \
+                         (run with --report to list every broken rule and cycle)",
+                        join_pages(&pages)
+                    ))
+                })?;
+                Ok(middle_page(&pages))
+            }
+            MiddlePageStrategy::Select => {
+                let middle = *select_nth_by(&mut pages, middle_idx, |a, b| {
+                    ordering.compare(*a, *b)
+                });
+                if !ordering.is_middle_page(&pages, middle) {
+                    return Err(AocError::Unsolvable(format!(
+                        "the selected page {middle} of update {} does not have exactly \
+                         {middle_idx} pages before and after it",
+                        join_pages(&pages)
+                    )));
+                }
+                Ok(middle.into())
+            }
+            MiddlePageStrategy::Count => pages
+                .iter()
+                .find(|page| ordering.is_middle_page(&pages, **page))
+                .map(|page| (*page).into())
+                .ok_or_else(|| {
+                    AocError::Unsolvable(format!(
+                        "no page of update {} has exactly {middle_idx} pages before and after it",
+                        join_pages(&pages)
+                    ))
+                }),
+        }
+    }
+
+    /// Fix the order of the incorrect updates and sum up their middle pages.
+    ///
+    /// # Arguments
+    ///    strategy: How to find the middle page of an incorrect update.
+    pub fn middle_page_sum_invalid(&self, strategy: MiddlePageStrategy) -> Result<u64> {
+        self.middle_page_sum_invalid_with(&self.ordering, strategy)
+    }
+
+    /// Fix the order of the incorrect updates by a set of page ordering rules and sum up their
+    /// middle pages.
+    fn middle_page_sum_invalid_with(
+        &self,
+        ordering: &PageOrdering,
+        strategy: MiddlePageStrategy,
+    ) -> Result<u64> {
+        let mut middle_page_sum_invalid: u64 = 0;
+        for pages in &self.updates {
+            if ordering.is_valid(pages) {
+                continue;
+            }
+
+            middle_page_sum_invalid += Self::fixed_middle_page(ordering, pages, strategy)?;
+        }
+
+        Ok(middle_page_sum_invalid)
+    }
+
+    /// Check the updates against the rules. Lists the rules broken by every invalid update and
+    /// the cycles in the rules between pages that appear together in an update.
+    pub fn diagnose(&self) -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+        for (pages, line) in self.updates.iter().zip(&self.update_lines) {
+            let violations = self.ordering.violations(pages);
+            if violations.is_empty() {
+                continue;
+            }
+
+            // The same cycle can show up in many updates, starting from any of its pages.
+            // Start every cycle from its smallest page so that duplicates can be skipped.
+            for mut cycle in self.ordering.cycles(pages) {
+                let smallest = (0..cycle.nodes.len())
+                    .min_by_key(|idx| cycle.nodes[*idx])
+                    .unwrap_or_default();
+                cycle.nodes.rotate_left(smallest);
+                if !diagnostics.cycles.contains(&cycle) {
+                    diagnostics.cycles.push(cycle);
+                }
+            }
+
+            diagnostics.invalid_updates.push(InvalidUpdate {
+                line: *line,
+                pages: pages.clone(),
+                violations,
+            });
+        }
+
+        diagnostics
+    }
+
+    /// Fix the order of the incorrect updates with a sorting algorithm. Returns the sum of
+    /// their middle pages and the work the algorithm did over all of the updates.
+    fn sort_invalid_updates(&self, algorithm: SortAlgorithm) -> (u64, SortStats) {
+        let mut middle_page_sum_invalid: u64 = 0;
+        let mut stats = SortStats::default();
+        for pages in &self.updates {
+            if self.ordering.is_valid(pages) {
+                continue;
+            }
+
+            let mut pages = pages.clone();
+            stats += algorithm.sort_instrumented(&mut pages, |a, b| self.ordering.compare(*a, *b));
+            middle_page_sum_invalid += middle_page(&pages);
+        }
+
+        (middle_page_sum_invalid, stats)
+    }
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("143"),
+            part_two: Some("123"),
+        },
+    ];
+    const VARIANTS: &'static [Variant<Self::Input>] = &[
+        Variant {
+            name: "select",
+            part: Part::Two,
+            solve: |input| Ok(input.middle_page_sum_invalid(MiddlePageStrategy::Select)?.into()),
+        },
+        Variant {
+            name: "count",
+            part: Part::Two,
+            solve: |input| Ok(input.middle_page_sum_invalid(MiddlePageStrategy::Count)?.into()),
+        },
+        Variant {
+            name: "hashed rules",
+            part: Part::One,
+            solve: |input| Ok(input.middle_page_sum_valid(input.hashed_ordering()).into()),
+        },
+        Variant {
+            name: "hashed rules",
+            part: Part::Two,
+            solve: |input| {
+                let ordering = input.hashed_ordering();
+                Ok(input.middle_page_sum_invalid_with(ordering, MiddlePageStrategy::Sort)?.into())
+            },
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Split by double new lines into rules list and updates.
+        let Some((update_rules, updates)) = input.split_once("\n\n") else {
+            return Err(AocError::malformed(
+                None,
+                "expected rules and updates separated by an empty line",
+            ));
+        };
+        let updates_line_offset = update_rules.lines().count() + 1;
+
+        let ordering = PageOrdering::parse(update_rules)?;
+
+        let mut parsed_updates: Vec<Vec<u32>> = Vec::new();
+        let mut update_lines = Vec::new();
+        for (line_idx, update) in updates.lines().enumerate() {
+            let line_idx = line_idx + updates_line_offset;
+            let mut pages = Vec::new();
+            for page in update.split(",") {
+                pages.push(parse_token::<u32>(update, line_idx, page)?);
+            }
+            parsed_updates.push(pages);
+            update_lines.push(line_idx + 1);
+        }
+
+        Ok(PrintQueue {
+            ordering,
+            updates: parsed_updates,
+            update_lines,
+            hashed_ordering: OnceLock::new(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(input.middle_page_sum_valid(&input.ordering).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(input.middle_page_sum_invalid(MiddlePageStrategy::Sort)?.into())
+    }
+
+    fn report(input: &Self::Input) -> Result<Option<String>> {
+        let mut report = input.diagnose().to_string();
+
+        // Work done by each sorting algorithm to fix the incorrect updates for part two.
+        report += &format!(
+            "\n{:<18} {:>12} {:>10} {:>12}\n",
+            "Algorithm", "Comparisons", "Swaps", "Middle sum"
+        );
+        for algorithm in SortAlgorithm::ALL {
+            let (middle_page_sum, stats) = input.sort_invalid_updates(algorithm);
+            writeln!(
+                report,
+                "{:<18} {:>12} {:>10} {:>12}",
+                algorithm.to_string(),
+                stats.comparisons,
+                stats.swaps,
+                middle_page_sum
+            )
+            .expect("Writing to a string should not fail");
+        }
+
+        Ok(Some(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_ordering() {
+        let mut ordering = PageOrdering::parse("1|2\n2|3\n").unwrap();
+        assert!(ordering.must_precede(1, 2));
+        assert!(!ordering.must_precede(2, 1));
+        assert!(!ordering.must_precede(1, 3));
+        assert!(ordering.is_valid(&[1, 2, 3]));
+        assert!(ordering.is_valid(&[3, 1]));
+        assert!(!ordering.is_valid(&[2, 1, 3]));
+
+        let mut pages = [3, 1, 2];
+        ordering.fix(&mut pages).unwrap();
+        assert_eq!(pages, [1, 2, 3]);
+
+        // Adding 3|1 closes a cycle, removing it again opens it.
+        assert!(ordering.add_rule(3, 1));
+        assert!(!ordering.add_rule(3, 1));
+        let mut pages = [3, 1, 2];
+        let cycle = ordering.fix(&mut pages).unwrap_err();
+        assert_eq!(cycle.nodes.len(), 3);
+        assert_eq!(pages, [3, 1, 2]);
+        assert!(ordering.remove_rule(3, 1));
+        assert!(!ordering.remove_rule(3, 1));
+        assert!(ordering.fix(&mut pages).is_ok());
+
+        assert!(PageOrdering::parse("1|2\n3-4\n").is_err());
+        assert!(PageOrdering::parse("1|x\n").is_err());
+    }
+
+    #[test]
+    fn test_dense_limit() {
+        // Rules between pages on both sides of the limit of the bit matrix, including pages
+        // on the boundaries of its words.
+        let pages = [0, 1, 63, 64, 65, 127, 128, 200, 255, 256, 300, 1000, 70000];
+        let mut rules = Vec::new();
+        for (idx, before) in pages.iter().enumerate() {
+            for after in pages.iter().skip(idx + 1).step_by(2) {
+                rules.push((*before, *after));
+            }
+        }
+
+        let orderings = [0, 64, 100, DENSE_PAGE_LIMIT].map(|limit| {
+            let mut ordering = PageOrdering::with_dense_limit(limit);
+            for (before, after) in &rules {
+                assert!(ordering.add_rule(*before, *after));
+            }
+            ordering
+        });
+
+        for ordering in &orderings {
+            let mut stored = ordering.rules().collect::<Vec<_>>();
+            stored.sort();
+            assert_eq!(stored, rules);
+            for before in pages {
+                for after in pages {
+                    let expected = rules.contains(&(before, after));
+                    assert_eq!(ordering.must_precede(before, after), expected);
+                }
+            }
+
+            assert!(ordering.is_valid(&pages));
+            let mut reversed = pages;
+            reversed.reverse();
+            assert!(!ordering.is_valid(&reversed));
+            assert!(!ordering.is_valid(&[1, 0]));
+            assert!(!ordering.is_valid(&[1000, 0]));
+            assert!(!ordering.is_valid(&[300, 64]));
+            assert!(ordering.is_valid(&[300, 63]));
+        }
+
+        let mut ordering = orderings[3].with_same_rules(64);
+        assert!(!ordering.is_valid(&[64, 0]));
+        assert!(ordering.remove_rule(0, 64));
+        assert!(ordering.remove_rule(0, 1000));
+        assert!(!ordering.remove_rule(0, 64));
+        assert!(!ordering.remove_rule(5, 6));
+        assert!(ordering.is_valid(&[64, 0]));
+        assert!(ordering.is_valid(&[1000, 0]));
+        assert_eq!(ordering.rules().count(), rules.len() - 2);
+    }
+
+    #[test]
+    fn test_sort_algorithms() {
+        let queue = Day5::parse(EXAMPLE).unwrap();
+        for algorithm in SortAlgorithm::ALL {
+            let (middle_page_sum, stats) = queue.sort_invalid_updates(algorithm);
+            assert_eq!(middle_page_sum, 123, "{algorithm}");
+            assert!(stats.comparisons > 0, "{algorithm}");
+        }
+        assert!(Day5::report(&queue).unwrap().unwrap().contains("merge sort"));
+    }
+
+    #[test]
+    fn test_middle_page_strategies() {
+        let queue = Day5::parse(EXAMPLE).unwrap();
+        let strategies = [MiddlePageStrategy::Sort, MiddlePageStrategy::Select, MiddlePageStrategy::Count];
+        for strategy in strategies {
+            assert_eq!(queue.middle_page_sum_invalid(strategy).unwrap(), 123, "{strategy:?}");
+        }
+
+        // Without a rule between 1 and 2 no page has exactly one page before and after it.
+        let queue = Day5::parse("1|3\n2|3\n\n3,1,2\n").unwrap();
+        assert_eq!(queue.middle_page_sum_invalid(MiddlePageStrategy::Sort).unwrap(), 2);
+        assert!(queue.middle_page_sum_invalid(MiddlePageStrategy::Select).is_err());
+        assert!(queue.middle_page_sum_invalid(MiddlePageStrategy::Count).is_err());
+
+        // Without a rule between 1 and 3 the middle page 2 is still known, but quickselect
+        // treats 1 and 3 as equal and selects the wrong page.
+        let queue = Day5::parse("1|2\n2|3\n\n3,1,2\n").unwrap();
+        assert_eq!(queue.middle_page_sum_invalid(MiddlePageStrategy::Sort).unwrap(), 2);
+        assert!(queue.middle_page_sum_invalid(MiddlePageStrategy::Select).is_err());
+        assert_eq!(queue.middle_page_sum_invalid(MiddlePageStrategy::Count).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_partial_rules() {
+        // Only the rules 1|2 and 2|3 are given, so a comparator that treats 1 and 3 as equal
+        // can leave the update in its original order.
+        let queue = Day5::parse("1|2\n2|3\n\n3,1,2\n").unwrap();
+        assert_eq!(Day5::part_two(&queue).unwrap(), Answer::from(2_u64));
+    }
+
+    #[test]
+    fn test_cyclic_rules() {
+        let queue = Day5::parse("1|2\n2|3\n3|1\n\n1,2,3\n4,5\n").unwrap();
+        assert_eq!(Day5::part_one(&queue).unwrap(), Answer::from(5_u64));
+        let err = Day5::part_two(&queue).unwrap_err();
+        assert!(matches!(err, AocError::Unsolvable(_)));
+        assert!(err.to_string().contains("update 1,2,3 can not be ordered"), "{err}");
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let queue = Day5::parse(EXAMPLE).unwrap();
+        let diagnostics = queue.diagnose();
+        let lines = diagnostics.invalid_updates.iter().map(|update| update.line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![26, 27, 28]);
+        assert_eq!(
+            diagnostics.invalid_updates[0].violations,
+            vec![Violation {
+                before: 97,
+                after: 75,
+                before_idx: 1,
+                after_idx: 0,
+            }]
+        );
+        assert!(diagnostics.cycles.is_empty());
+        assert!(diagnostics.to_string().contains("rule 97|75 is broken: 97 is page 2 but 75 is page 1"));
+
+        // The cycle 1 -> 2 -> 3 is found in both updates but only listed once. The rule 4|5 is
+        // not on a cycle.
+        let queue = Day5::parse("1|2\n2|3\n3|1\n4|5\n\n3,2,1\n2,3,1,5,4\n").unwrap();
+        let diagnostics = queue.diagnose();
+        assert_eq!(diagnostics.invalid_updates.len(), 2);
+        assert_eq!(diagnostics.invalid_updates[1].violations.len(), 2);
+        assert_eq!(diagnostics.cycles, vec![Cycle { nodes: vec![1, 2, 3] }]);
+        assert!(diagnostics.to_string().contains("  1 -> 2 -> 3 -> 1"));
+    }
+}
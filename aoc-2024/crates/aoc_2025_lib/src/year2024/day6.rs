@@ -0,0 +1,478 @@
+use aoc_common::{Direction4, Grid, Plane};
+use crate::{Answer, AocError, Example, Result, Solution};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Solution for day 6.
+pub struct Day6;
+
+/// The glyphs of a guard on the map of the lab and the direction the guard is facing.
+const GUARD_GLYPHS: [(char, Direction4); 4] = [
+    ('^', Direction4::Up),
+    ('>', Direction4::Right),
+    ('v', Direction4::Down),
+    ('<', Direction4::Left),
+];
+
+/// Return the direction of the guard drawn in a cell, or `None` if there is no guard.
+fn guard_direction(cell: char) -> Option<Direction4> {
+    GUARD_GLYPHS
+        .iter()
+        .find(|(glyph, _)| *glyph == cell)
+        .map(|(_, direction)| *direction)
+}
+
+/// A guard patrolling the lab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard<P> {
+    /// The initial position of the guard.
+    pub position: P,
+    /// The initial direction the guard is facing.
+    pub direction: Direction4,
+}
+
+/// What a single guard does when it is alone in the lab.
+#[derive(Debug, Clone)]
+pub struct GuardReport<P> {
+    /// The guard.
+    pub guard: Guard<P>,
+    /// The distinct positions the guard visits.
+    pub visited: HashSet<P>,
+    /// The positions where a new barrier traps the guard in a loop.
+    pub traps: HashSet<P>,
+}
+
+/// Struct to represent the lab. The lab can be laid out on any plane: a bounded grid as in
+/// the puzzle, a toroidal grid or an unbounded sparse grid. The lab has the following properties:
+pub struct Lab<G: Plane<Cell = char> = Grid<char>> {
+    /// Static properties of the lab.
+    ///
+    /// The grid of the lab.
+    grid: G,
+    /// The guards in the lab, row by row. Each guard is simulated on its own, as if the other
+    /// guards were not there.
+    guards: Vec<Guard<G::Pos>>,
+}
+
+impl<G: Plane<Cell = char> + Clone> Lab<G> {
+    /// Create a new instance of the lab. Every `^`, `>`, `v` or `<` on the grid is a guard
+    /// facing up, right, down or left. Returns an error if there is no guard.
+    ///
+    /// # Arguments
+    ///    grid: The grid of the lab.
+    fn new(grid: G) -> Result<Self> {
+        let guards = grid
+            .find_all_by(|cell| guard_direction(*cell).is_some())
+            .into_iter()
+            .map(|position| {
+                let cell = grid.cell(position).expect("Guard should be on the grid");
+                let direction = guard_direction(*cell).expect("Cell should hold a guard");
+                Guard { position, direction }
+            })
+            .collect::<Vec<_>>();
+
+        if guards.is_empty() {
+            return Err(AocError::malformed(
+                None,
+                "the lab has no guard, expected one of '^', '>', 'v' or '<'",
+            ));
+        }
+
+        Ok(Self { grid, guards })
+    }
+
+    /// Move guard by one unit in the current direction. Returns the new position of the guard,
+    /// or `None` if the guard leaves the lab. On an unbounded plane the guard leaves once nothing
+    /// but empty floor lies ahead.
+    fn move_guard(&self, guard_position: G::Pos, guard_direction: Direction4) -> Option<G::Pos> {
+        let offset = guard_direction.offset();
+        self.grid
+            .step(guard_position, offset)
+            .filter(|new_position| !self.grid.is_beyond(*new_position, offset))
+    }
+
+    /// Check if the cell at a position of the grid is a barrier.
+    fn is_barrier(grid: &G, position: G::Pos) -> bool {
+        grid.cell(position) == Some(&'#')
+    }
+
+    // Try to trap the guard by placing a barrier at the new barrier position.
+    // If the guard is trapped, return true. Otherwise, return false. A guard is trapped if it is
+    // forced to move in a loop.
+    //
+    // # Arguments
+    //     new_barrier: The position of the new barrier.
+    fn try_trap_guard(&self, new_barrier: G::Pos, starting_position: G::Pos, starting_direction: Direction4) -> bool {
+        let mut grid = self.grid.clone();
+        let mut guard_position = starting_position;
+        let mut guard_direction = starting_direction;
+        let mut turning_positions: HashSet<(G::Pos, Direction4)> = HashSet::new();
+        // Position where the guard last turned. On a toroidal grid the guard can loop without
+        // ever turning, by walking straight around the grid back to this position.
+        let mut straight_start = guard_position;
+
+        grid.set_cell(new_barrier, '#');
+
+        // Move the guard by one unit in the current direction until it leaves the lab.
+        while let Some(new_position) = self.move_guard(guard_position, guard_direction) {
+            if Self::is_barrier(&grid, new_position) {
+                // If the guard is turning at the same position, then it is trapped.
+                if !turning_positions.insert((guard_position, guard_direction)) {
+                    return true;
+                }
+
+                // Rotate the guard by 90 degrees if it encounters a barrier.
+                guard_direction = guard_direction.rotate_clockwise();
+                straight_start = guard_position;
+            } else {
+                // update the guard position.
+                guard_position = new_position;
+                if guard_position == straight_start {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Walk a guard until it leaves the lab or starts repeating its path. Returns every
+    /// position the guard visited mapped to the direction the guard was facing when it first
+    /// visited the position.
+    fn guard_path(&self, guard: Guard<G::Pos>) -> HashMap<G::Pos, Direction4> {
+        let mut visited_directions: HashMap<G::Pos, Direction4> = HashMap::new();
+        let mut guard_states: HashSet<(G::Pos, Direction4)> = HashSet::new();
+        let mut guard_position = guard.position;
+        let mut guard_direction = guard.direction;
+
+        // Solve path of guard. Move the guard by one unit in the current direction until it
+        // leaves the lab, or until it is back in a state it was already in, which can happen on
+        // a toroidal grid.
+        while let Some(new_position) = self.move_guard(guard_position, guard_direction) {
+            if !guard_states.insert((guard_position, guard_direction)) {
+                break;
+            }
+
+            // Track visited positions.
+            visited_directions.entry(guard_position).or_insert(guard_direction);
+
+            if Self::is_barrier(&self.grid, new_position) {
+
+                // Rotate the guard by 90 degrees if it encounters a barrier.
+                guard_direction = guard_direction.rotate_clockwise();
+            } else {
+                // update the guard position.
+                guard_position = new_position;
+            }
+        }
+
+        // Add the last position to the visited positions.
+        visited_directions.entry(guard_position).or_insert(guard_direction);
+
+        visited_directions
+    }
+
+    /// Find the positions where placing a single new barrier traps a guard in a loop. No
+    /// barrier can be placed where the guard starts, but the other guards are ignored just as
+    /// they are on the guard's path.
+    fn guard_traps(&self, guard: Guard<G::Pos>) -> HashSet<G::Pos> {
+        let visited_directions = self.guard_path(guard);
+        let mut guard_traps = HashSet::new();
+
+        // For each poisition visited (excluding the initial position), check if placing a barrier
+        // will put the guard in a loop.
+        for (position, direction) in visited_directions.iter() {
+            if *position == guard.position {
+                continue;
+            }
+
+            // Set starting direction to the direction the guard was facing when it visited the position,
+            // the new barrier position to the position the guard visited and starting position to the position just before the barrier.
+            let new_barrier_position = *position;
+            let starting_direction = *direction;
+            let starting_position = self
+                .move_guard(new_barrier_position, starting_direction.reverse())
+                .expect("Guard should have reached the position from inside the lab");
+            if self.try_trap_guard(new_barrier_position, starting_position, starting_direction) {
+                guard_traps.insert(new_barrier_position);
+            }
+        }
+
+        guard_traps
+    }
+
+    /// Count the distinct positions visited by any of the guards before leaving the lab.
+    fn count_visited_positions(&self) -> usize {
+        let mut visited_positions = HashSet::new();
+        for guard in &self.guards {
+            visited_positions.extend(self.guard_path(*guard).into_keys());
+        }
+
+        visited_positions.len()
+    }
+
+    /// Count the positions where placing a single new barrier traps any of the guards in a loop.
+    fn count_guard_traps(&self) -> usize {
+        let mut guard_traps = HashSet::new();
+        for guard in &self.guards {
+            guard_traps.extend(self.guard_traps(*guard));
+        }
+
+        guard_traps.len()
+    }
+
+    /// Simulate every guard on its own. Returns the positions visited by each guard and the
+    /// positions where a new barrier traps it.
+    pub fn guard_reports(&self) -> Vec<GuardReport<G::Pos>> {
+        self.guards
+            .iter()
+            .map(|guard| GuardReport {
+                guard: *guard,
+                visited: self.guard_path(*guard).into_keys().collect(),
+                traps: self.guard_traps(*guard),
+            })
+            .collect()
+    }
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+impl Solution for Day6 {
+    type Input = Lab;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("41"),
+            part_two: Some("6"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::parse(input)?;
+
+        Lab::new(grid)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_visited_positions().into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_guard_traps().into())
+    }
+
+    fn report(input: &Self::Input) -> Result<Option<String>> {
+        let mut report = format!(
+            "{:<6} {:>5} {:>5} {:<7} {:>8} {:>6}\n",
+            "Guard", "Row", "Col", "Facing", "Visited", "Traps"
+        );
+        // The combined row is the union of the positions of every guard, so the guards do not
+        // need to be simulated again.
+        let mut visited = HashSet::new();
+        let mut traps = HashSet::new();
+        for (idx, guard_report) in input.guard_reports().into_iter().enumerate() {
+            let guard = guard_report.guard;
+            writeln!(
+                report,
+                "{:<6} {:>5} {:>5} {:<7} {:>8} {:>6}",
+                idx + 1,
+                guard.position.row,
+                guard.position.col,
+                format!("{:?}", guard.direction),
+                guard_report.visited.len(),
+                guard_report.traps.len()
+            )
+            .expect("Writing to a string should not fail");
+            visited.extend(guard_report.visited);
+            traps.extend(guard_report.traps);
+        }
+        writeln!(
+            report,
+            "{:<6} {:>5} {:>5} {:<7} {:>8} {:>6}",
+            "All",
+            "",
+            "",
+            "",
+            visited.len(),
+            traps.len()
+        )
+        .expect("Writing to a string should not fail");
+
+        Ok(Some(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Position, SparseGrid, Topology};
+
+    #[test]
+    fn test_case_one() {
+        let input = vec![
+            vec!['.', '.', '.', '.', '#', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '#'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '#', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '#', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '#', '.', '.', '^', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '#', '.'],
+            vec!['#', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '#', '.', '.', '.'],
+        ];
+        let lab = Lab::new(Grid::from_rows(input).unwrap()).unwrap();
+        assert_eq!(Day6::part_one(&lab).unwrap(), Answer::from(41_usize));
+        assert_eq!(Day6::part_two(&lab).unwrap(), Answer::from(6_usize));
+    }
+
+    #[test]
+    fn test_case_two() {
+        let input = vec![
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '#'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '#', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '#', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '#', '.', '.', '^', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '#', '.'],
+            vec!['#', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '#', '.', '.', '.'],
+        ];
+        let lab = Lab::new(Grid::from_rows(input).unwrap()).unwrap();
+        assert_eq!(Day6::part_one(&lab).unwrap(), Answer::from(7_usize));
+        assert_eq!(Day6::part_two(&lab).unwrap(), Answer::from(0_usize));
+    }
+
+    #[test]
+    fn test_case_three() {
+        let input = vec![
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '#'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '#', '.', '#', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '#', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '^', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '.', '#', '#', '.'],
+            vec!['#', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['.', '.', '.', '.', '.', '.', '#', '.', '.', '.'],
+        ];
+        let lab = Lab::new(Grid::from_rows(input).unwrap()).unwrap();
+        assert_eq!(Day6::part_one(&lab).unwrap(), Answer::from(14_usize));
+        assert_eq!(Day6::part_two(&lab).unwrap(), Answer::from(2_usize));
+    }
+
+    #[test]
+    fn test_topologies() {
+        let grid = Grid::parse(EXAMPLE).unwrap();
+
+        // On an unbounded plane the guard leaves once it walks off the mapped area.
+        let lab = Lab::new(SparseGrid::from_grid(&grid, '.')).unwrap();
+        assert_eq!(lab.count_visited_positions(), 41);
+        assert_eq!(lab.count_guard_traps(), 6);
+
+        // On a torus the guard turns at the barrier, walks off the right edge, wraps around to the
+        // left edge and loops. Any new barrier leaves the guard looping.
+        let grid = Grid::parse(".#.\n...\n.^.").unwrap();
+        assert_eq!(Lab::new(grid.clone()).unwrap().count_visited_positions(), 3);
+        let lab = Lab::new(grid.with_topology(Topology::Toroidal)).unwrap();
+        assert_eq!(lab.count_visited_positions(), 4);
+        assert_eq!(lab.count_guard_traps(), 3);
+    }
+
+    #[test]
+    fn test_guard_glyphs() {
+        let visited = |map: &str| Day6::parse(map).unwrap().count_visited_positions();
+        assert_eq!(visited(".....\n.>..#\n....."), 4);
+        assert_eq!(visited(".v.\n...\n.#."), 3);
+        assert_eq!(visited("#..<."), 3);
+
+        let err = Day6::parse("..#\n...").err().unwrap();
+        assert!(matches!(err, AocError::MalformedInput { .. }));
+        assert!(err.to_string().contains("the lab has no guard"), "{err}");
+    }
+
+    #[test]
+    fn test_multiple_guards() {
+        // The guard from the example plus a guard in the top left corner facing down, which
+        // walks down to the barrier in the bottom row, turns left and leaves.
+        let map = EXAMPLE.replacen('.', "v", 1);
+        let lab = Day6::parse(&map).unwrap();
+        let reports = lab.guard_reports();
+        assert_eq!(reports.len(), 2);
+        let guard = Guard { position: Position::new(0, 0), direction: Direction4::Down };
+        assert_eq!(reports[0].guard, guard);
+        assert_eq!(reports[0].visited.len(), 8);
+        let guard = Guard { position: Position::new(6, 4), direction: Direction4::Up };
+        assert_eq!(reports[1].guard, guard);
+        assert_eq!((reports[1].visited.len(), reports[1].traps.len()), (41, 6));
+
+        // Both guards are simulated on their own, so the combined counts are the size of the
+        // union of the positions of each guard.
+        let single = |keep: usize| {
+            let mut grid = lab.grid.clone();
+            grid.set_cell(lab.guards[1 - keep].position, '.');
+            Lab::new(grid).unwrap()
+        };
+        let (first, second) = (single(0), single(1));
+        let mut visited = first.guard_path(first.guards[0]).into_keys().collect::<HashSet<_>>();
+        visited.extend(second.guard_path(second.guards[0]).into_keys());
+        assert_eq!(lab.count_visited_positions(), visited.len());
+        let mut traps = first.guard_traps(first.guards[0]);
+        traps.extend(second.guard_traps(second.guards[0]));
+        assert_eq!(lab.count_guard_traps(), traps.len());
+        assert_eq!((visited.len(), traps.len()), (49, 6));
+
+        let report = Day6::report(&lab).unwrap().unwrap();
+        let combined = report.lines().last().unwrap().split_whitespace().collect::<Vec<_>>();
+        assert_eq!(combined, ["All", "49", "6"]);
+
+        // A guard starting on one of the traps of the example guard does not stop a barrier
+        // being placed there for the example guard.
+        let mut grid = Day6::parse(EXAMPLE).unwrap().grid;
+        grid.set_cell(Position::new(8, 3), '<');
+        let lab = Lab::new(grid).unwrap();
+        let reports = lab.guard_reports();
+        let guard = Guard { position: Position::new(6, 4), direction: Direction4::Up };
+        let report = reports.iter().find(|report| report.guard == guard).unwrap();
+        assert_eq!((report.visited.len(), report.traps.len()), (41, 6));
+        let other = reports.iter().find(|report| report.guard != guard).unwrap();
+        assert_eq!(other.guard.position, Position::new(8, 3));
+
+        let single = |keep: Position| {
+            let mut grid = lab.grid.clone();
+            for guard in lab.guards.iter().filter(|guard| guard.position != keep) {
+                grid.set_cell(guard.position, '.');
+            }
+            Lab::new(grid).unwrap()
+        };
+        let mut visited = HashSet::new();
+        let mut traps = HashSet::new();
+        for guard in &lab.guards {
+            let single = single(guard.position);
+            visited.extend(single.guard_path(single.guards[0]).into_keys());
+            traps.extend(single.guard_traps(single.guards[0]));
+        }
+        assert_eq!(lab.count_visited_positions(), visited.len());
+        assert_eq!(lab.count_guard_traps(), traps.len());
+
+        let report = Day6::report(&lab).unwrap().unwrap();
+        let combined = report.lines().last().unwrap().split_whitespace().collect::<Vec<_>>();
+        assert_eq!(combined, ["All", &visited.len().to_string(), &traps.len().to_string()]);
+    }
+}
@@ -0,0 +1,187 @@
+use aoc_common::parse_token;
+use crate::{Answer, Example, AocError, Result, Solution};
+
+/// Solution for day 7.
+pub struct Day7;
+
+/// Trait to concatenate two u64 values.
+trait Concat {
+    fn concat(self, other: u64) -> Option<u64>;
+}
+
+/// Implementation of the Concat trait for u64.
+impl Concat for u64 {
+    /// Concatenates two u64 values. Returns None if the result does not fit in a u64.
+    fn concat(self, other: u64) -> Option<u64> {
+        /// Counts the number of digits in a u64 value.
+        fn count_digits(val: u64) -> u32 {
+            let mut res = 1;
+            let mut val = val/10;
+            while val != 0 {
+                res += 1;
+                val /= 10;
+            }
+            res
+        }
+
+        let other_digits = count_digits(other);
+        self.checked_mul(10_u64.checked_pow(other_digits)?)?.checked_add(other)
+    }
+}
+
+/// Returns true if the test is calibrated, false otherwise. A test is calibration if there
+/// exist an equation evaluated from left to right using between the test paramters, 
+/// using a combination of the * and + operators, and the || operator if `allow_concat` is set.
+fn is_calibrated(test_value: u64, test_parameters: &[u64], allow_concat: bool) -> bool {
+    // Solution for this problem is essentially a depth first search on
+    // a binary tree. Each node in the tree is the cumulative result of the
+    // equation with the left and right child being the result of multiplying or
+    // adding the value of the node to the next parameter.
+    //
+    // Using a stack data structure to solve this with each element in the stack being
+    // a tuple the depth in the tree and the value of the node. A value of None means the
+    // node went past the test value, possibly overflowing.
+    let mut stack: Vec<(usize, Option<u64>)> = Vec::new();
+
+    // No operator makes the value smaller, except multiplying by zero. A node past the test
+    // value can be dropped unless a zero parameter follows it, in which case only the fact
+    // that it is past the test value matters.
+    let mut zero_after = vec![false; test_parameters.len()];
+    for index in (0..test_parameters.len().saturating_sub(1)).rev() {
+        zero_after[index] = zero_after[index + 1] || test_parameters[index + 1] == 0;
+    }
+    let push = |stack: &mut Vec<(usize, Option<u64>)>, index: usize, value: Option<u64>| {
+        match value.filter(|value| *value <= test_value) {
+            Some(value) => stack.push((index, Some(value))),
+            None if zero_after[index] => stack.push((index, None)),
+            None => {}
+        }
+    };
+
+    // Push left most param to stack.
+    push(&mut stack, 0, Some(test_parameters[0]));
+
+    while let Some(top) = stack.pop() {
+        let index = top.0 + 1;
+        let cumulative_value = top.1;
+        if index < test_parameters.len() {
+            let right_param = test_parameters[index];
+            let product = match cumulative_value {
+                Some(value) => value.checked_mul(right_param),
+                None if right_param == 0 => Some(0),
+                None => None,
+            };
+            push(&mut stack, index, product);
+            let sum = cumulative_value.and_then(|value| value.checked_add(right_param));
+            push(&mut stack, index, sum);
+            if allow_concat {
+                let concatenation = cumulative_value.and_then(|value| value.concat(right_param));
+                push(&mut stack, index, concatenation);
+            }
+        } else if cumulative_value == Some(test_value) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Sum the test values of the calibrated equations.
+fn calibrated_sum(equations: &[(u64, Vec<u64>)], allow_concat: bool) -> u64 {
+    let mut calibrated_sum = 0;
+
+    for (test_value, test_parameters) in equations {
+        if is_calibrated(*test_value, test_parameters, allow_concat) {
+            calibrated_sum += test_value;
+        }
+    }
+
+    calibrated_sum
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+impl Solution for Day7 {
+    /// List of equations. Each equation is the test value and the test parameters.
+    type Input = Vec<(u64, Vec<u64>)>;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("3749"),
+            part_two: Some("11387"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_idx, line)| {
+                let equation_components: Vec<&str> = line.split(": ").collect();
+                if equation_components.len() != 2 {
+                    return Err(AocError::malformed(
+                        Some(line_idx + 1),
+                        "expected an equation of the form \"<value>: <parameters>\"",
+                    ));
+                }
+
+                let test_value = parse_token::<u64>(line, line_idx, equation_components[0])?;
+                let test_parameters: Vec<u64> = equation_components[1]
+                    .split(" ")
+                    .map(|param| parse_token::<u64>(line, line_idx, param))
+                    .collect::<Result<_>>()?;
+                Ok((test_value, test_parameters))
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(calibrated_sum(input, false).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(calibrated_sum(input, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concatenation_terst() {
+        assert!(20_u64.concat(55) == Some(2055));
+        assert!(37473_u64.concat(93282) == Some(3747393282));
+        assert!(0_u64.concat(0) == Some(0));
+        assert!(1_u64.concat(0) == Some(10));
+        assert!(u64::MAX.concat(1).is_none());
+        assert!(1_u64.concat(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn overflow_test() {
+        // Every operator overflows, so the equation can not be calibrated.
+        let input = Day7::parse("5: 10000000000 10000000000").unwrap();
+        assert_eq!(Day7::part_one(&input).unwrap(), Answer::from(0_u64));
+        assert_eq!(Day7::part_two(&input).unwrap(), Answer::from(0_u64));
+
+        // A value that overflowed can still be multiplied by zero.
+        let input = Day7::parse("7: 10000000000 10000000000 0 7").unwrap();
+        assert_eq!(Day7::part_one(&input).unwrap(), Answer::from(7_u64));
+        assert_eq!(Day7::part_two(&input).unwrap(), Answer::from(7_u64));
+
+        // Values past the test value are still correct when they do not overflow.
+        let input = Day7::parse("18446744073709551615: 18446744073709551614 1").unwrap();
+        assert_eq!(Day7::part_one(&input).unwrap(), Answer::from(u64::MAX));
+    }
+}
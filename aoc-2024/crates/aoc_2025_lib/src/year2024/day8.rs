@@ -0,0 +1,123 @@
+use aoc_common::{Grid, Position};
+use crate::{Answer, Example, Result, Solution};
+
+use std::collections::{HashMap, HashSet};
+
+/// Solution for day 8.
+pub struct Day8;
+
+/// Struct to represent the city. Specifically antennas on the roofs of buildings.
+pub struct City {
+    /// Grid representing the city with antennas on the roofs of buildings.
+    map: Grid<char>,
+}
+
+impl City {
+    /// Create a new city with the given antenna map.
+    fn new(map: Grid<char>) -> City {
+        City { map }
+    }
+
+    /// Given a pair of satellites of the same kind. Find distinct antinodes. Without resonant
+    /// harmonics, antinodes only occur at the first point past each antenna.
+    fn count_antenna_pair_antinodes(&self, antenna_a: &Position, antenna_b: &Position, resonant: bool, antinodes: &mut HashSet<Position>) {
+        // Calculate distance between two antennas.
+        let distance = *antenna_a - *antenna_b;
+
+        // Antinodes lie on the line through both antennas, starting one distance past each of them.
+        let antinodes_one = self.map.ray(*antenna_a, distance).skip(1);
+        let antinodes_two = self.map.ray(*antenna_b, -distance).skip(1);
+
+        if !resonant {
+            antinodes.extend(antinodes_one.take(1).chain(antinodes_two.take(1)));
+            return;
+        }
+
+        antinodes.extend(antinodes_one.chain(antinodes_two));
+    }
+
+
+    /// Count the number of antinodes in the city. An antinode occurs at any point that is perfectly in
+    /// line with two antennas of the same kind. Without resonant harmonics, the point must also be
+    /// twice as far from one antenna as from the other.
+    fn count_antinodes(&self, resonant: bool) -> usize {
+        let mut antenna_map: HashMap<char, Vec<Position>> = HashMap::new();
+        let mut antinodes: HashSet<Position> = HashSet::new(); // to store unique antinodes.
+
+        // Group antennas of the same kind into a map.
+        for (position, node) in self.map.iter() {
+            if *node == '.' {
+                // Node is not an antenna
+                continue;
+            }
+
+            antenna_map.entry(*node).or_default().push(position);
+        }
+
+        // For each kind of antenna, count the possible antinodes for each pair of distinct antennas.
+        for matching_antennas in antenna_map.values() {
+            // Any two antennas of the same kind will form a line. Loop through each pair of 
+            // distinct antennas of the same kind to find the antinodes within the boundaries of the city
+            for antenna_a in matching_antennas {
+                for antenna_b in matching_antennas {
+                    if antenna_a ==  antenna_b {
+                        // One antenna cannot form a line.
+                        continue;
+                    }
+                    // With resonant harmonics, the two antennas in a pair are also antinodes since
+                    // they are inline with one another.
+                    if resonant {
+                        antinodes.insert(*antenna_a);
+                        antinodes.insert(*antenna_b);
+                    }
+
+                    self.count_antenna_pair_antinodes(antenna_a, antenna_b, resonant, &mut antinodes);
+                }
+            }
+        }
+
+        antinodes.len()
+    }
+}
+
+/// Example from the puzzle description.
+const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+impl Solution for Day8 {
+    type Input = City;
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: EXAMPLE,
+            part_one: Some("14"),
+            part_two: Some("34"),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Parse input into a grid of characters.
+        let map = Grid::parse(input)?;
+
+        Ok(City::new(map))
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_antinodes(false).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        Ok(input.count_antinodes(true).into())
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Signed distance between two positions, in rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Offset {
+    /// Create a new offset.
+    ///
+    /// # Arguments
+    ///    row: The number of rows to move down, negative to move up.
+    ///    col: The number of columns to move right, negative to move left.
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+
+    fn sub(self, other: Offset) -> Offset {
+        Offset::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, factor: isize) -> Offset {
+        Offset::new(self.row * factor, self.col * factor)
+    }
+}
+
+/// The four orthogonal directions, with up being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Offset of a single step in the direction.
+    pub const fn offset(self) -> Offset {
+        match self {
+            Direction4::Up => Offset::new(-1, 0),
+            Direction4::Right => Offset::new(0, 1),
+            Direction4::Down => Offset::new(1, 0),
+            Direction4::Left => Offset::new(0, -1),
+        }
+    }
+
+    /// Rotate by 90 degrees clockwise.
+    pub const fn rotate_clockwise(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    /// Rotate by 90 degrees counter-clockwise.
+    pub const fn rotate_counter_clockwise(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Left,
+            Direction4::Right => Direction4::Up,
+            Direction4::Down => Direction4::Right,
+            Direction4::Left => Direction4::Down,
+        }
+    }
+
+    /// The opposite direction.
+    pub const fn reverse(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Down,
+            Direction4::Right => Direction4::Left,
+            Direction4::Down => Direction4::Up,
+            Direction4::Left => Direction4::Right,
+        }
+    }
+}
+
+/// The four orthogonal and four diagonal directions, with up being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Offset of a single step in the direction.
+    pub const fn offset(self) -> Offset {
+        match self {
+            Direction8::Up => Offset::new(-1, 0),
+            Direction8::UpRight => Offset::new(-1, 1),
+            Direction8::Right => Offset::new(0, 1),
+            Direction8::DownRight => Offset::new(1, 1),
+            Direction8::Down => Offset::new(1, 0),
+            Direction8::DownLeft => Offset::new(1, -1),
+            Direction8::Left => Offset::new(0, -1),
+            Direction8::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    /// Rotate by 45 degrees clockwise.
+    pub const fn rotate_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotate by 45 degrees counter-clockwise.
+    pub const fn rotate_counter_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The opposite direction.
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::Up,
+            Direction4::Right => Direction8::Right,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset() {
+        let offset = Offset::new(1, -2);
+        assert_eq!(offset + Offset::new(2, 2), Offset::new(3, 0));
+        assert_eq!(offset - Offset::new(2, 2), Offset::new(-1, -4));
+        assert_eq!(-offset, Offset::new(-1, 2));
+        assert_eq!(offset * 3, Offset::new(3, -6));
+    }
+
+    #[test]
+    fn test_direction4() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction.rotate_clockwise().rotate_counter_clockwise(), direction);
+            assert_eq!(direction.rotate_clockwise().rotate_clockwise(), direction.reverse());
+            assert_eq!(direction.offset() + direction.reverse().offset(), Offset::default());
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+        assert_eq!(Direction4::Left.rotate_clockwise(), Direction4::Up);
+    }
+
+    #[test]
+    fn test_direction8() {
+        for direction in Direction8::ALL {
+            assert_eq!(direction.rotate_clockwise().rotate_counter_clockwise(), direction);
+            assert_eq!(direction.offset() + direction.reverse().offset(), Offset::default());
+        }
+        assert_eq!(Direction8::UpLeft.rotate_clockwise(), Direction8::Up);
+        assert_eq!(Direction8::Up.rotate_counter_clockwise(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Result type used by the parsing helpers and the solutions.
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Errors that can occur while reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    /// The puzzle input could not be read.
+    Io {
+        /// The file that was being read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: io::Error,
+    },
+    /// A value in the puzzle input could not be parsed.
+    Parse {
+        /// The file the input was read from, if known.
+        file: Option<PathBuf>,
+        /// The line of the value, starting from 1.
+        line: usize,
+        /// The column of the value, starting from 1.
+        column: usize,
+        /// Description of what went wrong.
+        message: String,
+    },
+    /// The puzzle input does not have the expected structure.
+    MalformedInput {
+        /// The file the input was read from, if known.
+        file: Option<PathBuf>,
+        /// The offending line, starting from 1, if the problem is with a single line.
+        line: Option<usize>,
+        /// Description of what went wrong.
+        message: String,
+    },
+    /// The puzzle input is well formed but the puzzle has no solution.
+    Unsolvable(String),
+}
+
+impl AocError {
+    /// Create an error for a value that could not be parsed.
+    ///
+    /// # Arguments
+    ///    line: The line of the value, starting from 1.
+    ///    column: The column of the value, starting from 1.
+    ///    message: Description of what went wrong.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            file: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for input that does not have the expected structure.
+    ///
+    /// # Arguments
+    ///    line: The offending line, starting from 1, if the problem is with a single line.
+    ///    message: Description of what went wrong.
+    pub fn malformed(line: Option<usize>, message: impl Into<String>) -> Self {
+        AocError::MalformedInput {
+            file: None,
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Attach the file the puzzle input was read from to the error.
+    pub fn with_file(mut self, path: &Path) -> Self {
+        match &mut self {
+            AocError::Parse { file, .. } | AocError::MalformedInput { file, .. } => {
+                *file = Some(path.to_path_buf());
+            }
+            AocError::Io { .. } | AocError::Unsolvable(_) => {}
+        }
+        self
+    }
+}
+
+/// Write the `file:line:column: ` prefix of a diagnostic. Input that was not read
+/// from a file is reported as `<input>`.
+fn write_location(
+    f: &mut fmt::Formatter<'_>,
+    file: &Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+) -> fmt::Result {
+    match file {
+        Some(file) => write!(f, "{}", file.display())?,
+        None => write!(f, "<input>")?,
+    }
+    if let Some(line) = line {
+        write!(f, ":{line}")?;
+    }
+    if let Some(column) = column {
+        write!(f, ":{column}")?;
+    }
+    write!(f, ": ")
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            AocError::Parse { file, line, column, message } => {
+                write_location(f, file, Some(*line), Some(*column))?;
+                write!(f, "{message}")
+            }
+            AocError::MalformedInput { file, line, message } => {
+                write_location(f, file, *line, None)?;
+                write!(f, "malformed input: {message}")
+            }
+            AocError::Unsolvable(message) => write!(f, "no solution: {message}"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = AocError::parse(3, 7, "invalid digit").with_file(Path::new("inputs/day1.txt"));
+        assert_eq!(err.to_string(), "inputs/day1.txt:3:7: invalid digit");
+
+        let err = AocError::malformed(Some(2), "expected two numbers");
+        assert_eq!(err.to_string(), "<input>:2: malformed input: expected two numbers");
+
+        let err = AocError::malformed(None, "empty grid");
+        assert_eq!(err.to_string(), "<input>: malformed input: empty grid");
+    }
+}
@@ -0,0 +1,470 @@
+use crate::direction::{Direction8, Offset};
+use crate::error::{AocError, Result};
+use crate::plane::Plane;
+use std::fmt;
+use std::ops::{Index, IndexMut, Sub};
+
+/// Struct to represent a position in the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    /// Create a new instance of the position.
+    ///
+    /// # Arguments
+    ///    row: The row index of the position.
+    ///    col: The column index of the position.
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Move the position by an offset. Returns `None` if the row or column would become
+    /// negative.
+    pub fn checked_add(self, offset: Offset) -> Option<Position> {
+        Some(Position::new(
+            self.row.checked_add_signed(offset.row)?,
+            self.col.checked_add_signed(offset.col)?,
+        ))
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    /// Offset that moves `other` to `self`.
+    fn sub(self, other: Position) -> Offset {
+        Offset::new(
+            self.row as isize - other.row as isize,
+            self.col as isize - other.col as isize,
+        )
+    }
+}
+
+/// How a grid treats positions stepped past its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    /// The grid has a hard border. Stepping past an edge leaves the grid.
+    #[default]
+    Bounded,
+    /// The grid is a torus. Stepping past an edge wraps around to the opposite edge.
+    Toroidal,
+}
+
+/// A rectangular grid of cells, stored row by row in a single vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+    topology: Topology,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its rows. The grid must have at least one column and every row
+    /// must have the same length.
+    ///
+    /// # Arguments
+    ///    rows: The rows of the grid, from top to bottom.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(AocError::malformed(None, "grid is empty"));
+        }
+
+        let row_count = rows.len();
+        let mut cells = Vec::with_capacity(row_count * cols);
+        for (row_idx, row) in rows.into_iter().enumerate() {
+            if row.len() != cols {
+                return Err(AocError::malformed(
+                    Some(row_idx + 1),
+                    format!("expected {cols} columns, found {}", row.len()),
+                ));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            cells,
+            rows: row_count,
+            cols,
+            topology: Topology::Bounded,
+        })
+    }
+
+    /// Parse the puzzle input into a grid, converting every character with `parse_cell`.
+    /// The grid must have at least one row and every row must have the same length.
+    ///
+    /// # Arguments
+    ///    input: The puzzle input.
+    ///    parse_cell: Converts a character of the input into a cell.
+    pub fn parse_with(input: &str, mut parse_cell: impl FnMut(char) -> T) -> Result<Self> {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut parse_cell).collect())
+                .collect(),
+        )
+    }
+
+    /// Set how the grid treats positions stepped past its edges.
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// How the grid treats positions stepped past its edges.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Number of rows of the grid.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns of the grid.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Check if a position is inside the grid.
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.rows && position.col < self.cols
+    }
+
+    /// Convert signed coordinates into a position. Returns `None` if the coordinates are
+    /// outside the grid.
+    ///
+    /// # Arguments
+    ///    row: The row index, which may be negative.
+    ///    col: The column index, which may be negative.
+    pub fn position(&self, row: isize, col: isize) -> Option<Position> {
+        let position = Position::new(usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        self.contains(position).then_some(position)
+    }
+
+    /// Move a position by an offset, following the topology of the grid. Returns `None` if
+    /// the position is outside the grid, or if the new position is outside a bounded grid.
+    pub fn step(&self, position: Position, offset: Offset) -> Option<Position> {
+        match self.topology {
+            Topology::Bounded => self.bounded_step(position, offset),
+            Topology::Toroidal => {
+                if !self.contains(position) {
+                    return None;
+                }
+                let wrap = |index: usize, offset: isize, size: usize| {
+                    (index as isize + offset).rem_euclid(size as isize) as usize
+                };
+                Some(Position::new(
+                    wrap(position.row, offset.row, self.rows),
+                    wrap(position.col, offset.col, self.cols),
+                ))
+            }
+        }
+    }
+
+    /// Move a position by an offset as if the grid was bounded.
+    fn bounded_step(&self, position: Position, offset: Offset) -> Option<Position> {
+        position
+            .checked_add(offset)
+            .filter(|position| self.contains(*position))
+    }
+
+    /// Get the cell at a position, or `None` if the position is outside the grid.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.row * self.cols + position.col])
+    }
+
+    /// Get a mutable reference to the cell at a position, or `None` if the position is
+    /// outside the grid.
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.cols + position.col])
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| Position::new(row, col)))
+    }
+
+    /// Iterate over the cells of the grid and their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Get a row of the grid. Panics if the row is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {row} is outside a grid of {} rows", self.rows);
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Iterate over the rows of the grid, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    /// Iterate over the cells of a column, from top to bottom. Panics if the column is
+    /// outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} is outside a grid of {} columns", self.cols);
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    /// Iterate over the columns of the grid, from left to right.
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Iterate over the positions of the ray starting at `start` and moving by `offset`,
+    /// following the topology of the grid. The ray ends when it leaves the grid or, on a
+    /// toroidal grid, when it wraps back around to `start`.
+    pub fn ray(&self, start: Position, offset: Offset) -> impl Iterator<Item = Position> + '_ {
+        let start = Some(start).filter(|position| self.contains(*position));
+        let mut next = start;
+        std::iter::from_fn(move || {
+            let position = next?;
+            next = self
+                .step(position, offset)
+                .filter(|next| Some(*next) != start);
+            Some(position)
+        })
+    }
+
+    /// Iterate over the cells of the line starting at `start` in the given direction, up to
+    /// the edge of the grid. Lines never wrap, whatever the topology of the grid.
+    fn line(&self, start: Position, direction: Direction8) -> impl Iterator<Item = &T> {
+        let start = Some(start).filter(|position| self.contains(*position));
+        std::iter::successors(start, move |position| {
+            self.bounded_step(*position, direction.offset())
+        })
+        .map(|position| &self[position])
+    }
+
+    /// Iterate over the diagonals running from top left to bottom right. The diagonals
+    /// start at the bottom left corner of the grid and end at the top right corner.
+    pub fn iter_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.rows)
+            .rev()
+            .map(|row| Position::new(row, 0))
+            .chain((1..self.cols).map(|col| Position::new(0, col)));
+        starts.map(|start| self.line(start, Direction8::DownRight))
+    }
+
+    /// Iterate over the diagonals running from top right to bottom left. The diagonals
+    /// start at the top left corner of the grid and end at the bottom right corner.
+    pub fn iter_anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_col = self.cols - 1;
+        let starts = (0..self.cols)
+            .map(|col| Position::new(0, col))
+            .chain((1..self.rows).map(move |row| Position::new(row, last_col)));
+        starts.map(|start| self.line(start, Direction8::DownLeft))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Find the first position, row by row, of a cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Position> {
+        self.find_all(value).next()
+    }
+
+    /// Iterate over the positions, row by row, of every cell equal to `value`.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+}
+
+impl<T> Plane for Grid<T> {
+    type Cell = T;
+    type Pos = Position;
+
+    fn cell(&self, position: Position) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn set_cell(&mut self, position: Position, cell: T) {
+        if let Some(old_cell) = self.get_mut(position) {
+            *old_cell = cell;
+        }
+    }
+
+    fn step(&self, position: Position, offset: Offset) -> Option<Position> {
+        Grid::step(self, position, offset)
+    }
+
+    fn is_beyond(&self, _position: Position, _offset: Offset) -> bool {
+        false
+    }
+
+    fn find(&self, cell: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        Grid::find(self, cell)
+    }
+
+    fn find_all_by<F>(&self, mut predicate: F) -> Vec<Position>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Parse the puzzle input into a grid of characters. The grid must have at least one
+    /// row and every row must have the same length.
+    ///
+    /// # Arguments
+    ///    input: The puzzle input.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, |cell| cell)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {position:?} is outside a grid of {} rows and {} columns",
+                self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} is outside a grid of {rows} rows and {cols} columns")
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_idx, row) in self.iter_rows().enumerate() {
+            if row_idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("abc\ndef\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Position::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        assert!(matches!(Grid::parse(""), Err(AocError::MalformedInput { .. })));
+        assert!(matches!(
+            Grid::parse("ab\nc"),
+            Err(AocError::MalformedInput { line: Some(2), .. })
+        ));
+
+        let digits = Grid::parse_with("12\n34", |cell| cell.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.iter_rows().collect::<Vec<_>>(), vec![&[1, 2], &[3, 4]]);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::parse("ab\ncd").unwrap();
+        assert_eq!(grid.get(Position::new(0, 1)), Some(&'b'));
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 2)), None);
+        assert_eq!(grid.position(-1, 0), None);
+        assert_eq!(grid.position(1, 1), Some(Position::new(1, 1)));
+        assert_eq!(grid.step(Position::new(0, 0), Offset::new(1, 1)), Some(Position::new(1, 1)));
+        assert_eq!(grid.step(Position::new(0, 0), Offset::new(-1, 0)), None);
+        assert_eq!(grid.step(Position::new(1, 1), Offset::new(0, 1)), None);
+        assert_eq!(Position::new(0, 2) - Position::new(1, 0), Offset::new(-1, 2));
+        assert_eq!(Position::new(0, 0).checked_add(Offset::new(0, -1)), None);
+
+        grid[Position::new(1, 0)] = 'x';
+        assert_eq!(grid.to_string(), "ab\nxd");
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(collect(grid.iter_columns()), vec!["ad", "be", "cf"]);
+        assert_eq!(collect(grid.iter_diagonals()), vec!["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.iter_anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+        assert_eq!(
+            grid.ray(Position::new(0, 0), Offset::new(0, 2)).collect::<Vec<_>>(),
+            vec![Position::new(0, 0), Position::new(0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("a#\n#b").unwrap();
+        assert_eq!(grid.find(&'#'), Some(Position::new(0, 1)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![Position::new(0, 1), Position::new(1, 0)]
+        );
+        assert_eq!(
+            grid.find_all_by(|cell| cell.is_alphabetic()),
+            vec![Position::new(0, 0), Position::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_toroidal() {
+        let grid = Grid::parse("abc\ndef").unwrap().with_topology(Topology::Toroidal);
+        assert_eq!(grid.step(Position::new(0, 0), Offset::new(-1, -1)), Some(Position::new(1, 2)));
+        assert_eq!(grid.step(Position::new(1, 2), Offset::new(0, 4)), Some(Position::new(1, 0)));
+        assert_eq!(grid.step(Position::new(2, 0), Offset::new(0, 1)), None);
+        assert_eq!(grid.neighbours8(Position::new(0, 0)).count(), 8);
+        assert_eq!(grid.ray(Position::new(0, 1), Offset::new(0, 1)).count(), 3);
+        assert_eq!(grid.ray(Position::new(0, 1), Offset::new(0, 0)).count(), 1);
+
+        // Lines stop at the edges whatever the topology.
+        assert_eq!(collect(grid.iter_diagonals()), vec!["d", "ae", "bf", "c"]);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        let corner = Position::new(0, 0);
+        assert_eq!(
+            grid.neighbours4(corner).collect::<Vec<_>>(),
+            vec![Position::new(0, 1), Position::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.neighbours4(Position::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+    }
+}
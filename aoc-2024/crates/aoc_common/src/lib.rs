@@ -0,0 +1,25 @@
+//! Helpers shared by the Advent of Code solutions of every year: the error type of the
+//! solutions, bounded, toroidal and unbounded grids, positions and directions, parsing of the
+//! puzzle input, sorting and topological sorting.
+
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod parse;
+pub mod plane;
+pub mod sort;
+pub mod sparse;
+pub mod topo;
+
+pub use direction::{Direction4, Direction8, Offset};
+pub use error::{AocError, Result};
+pub use grid::{Grid, Position, Topology};
+pub use parse::parse_token;
+pub use plane::Plane;
+pub use sort::{
+    heap_sort, insertion_sort, merge_sort, quick_sort, quick_sort_by_key, quick_sort_stable,
+    quick_sort_stable_by_key, quick_sort_unstable, quick_sort_unstable_by_key, select_nth_by,
+    SortAlgorithm, SortStats,
+};
+pub use sparse::{Point, SparseGrid};
+pub use topo::{topological_sort, Cycle};
@@ -0,0 +1,36 @@
+use crate::error::{AocError, Result};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parse a token taken from a line of the puzzle input. On failure the error reports the
+/// line and the column the token starts at.
+///
+/// # Arguments
+///    line: The line the token was taken from.
+///    line_idx: The index of the line in the input, starting from 0.
+///    token: The token to parse. Must be a slice of `line`.
+pub fn parse_token<T>(line: &str, line_idx: usize, token: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse::<T>().map_err(|err| {
+        let column = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize) + 1;
+        AocError::parse(line_idx + 1, column, format!("invalid value {token:?}: {err}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token() {
+        let line = "12   x4";
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        assert_eq!(parse_token::<i64>(line, 0, tokens[0]).unwrap(), 12);
+
+        let err = parse_token::<i64>(line, 4, tokens[1]).unwrap_err();
+        assert!(matches!(err, AocError::Parse { line: 5, column: 6, .. }));
+    }
+}
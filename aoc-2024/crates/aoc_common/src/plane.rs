@@ -0,0 +1,56 @@
+use crate::direction::{Direction4, Direction8, Offset};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A two dimensional map of cells that can be walked one step at a time. Implemented by
+/// [`Grid`](crate::Grid) for bounded and toroidal grids and by
+/// [`SparseGrid`](crate::SparseGrid) for unbounded planes, so solvers written against it run
+/// unchanged on any topology.
+pub trait Plane {
+    /// The type of the cells.
+    type Cell;
+    /// The type of the positions of the cells.
+    type Pos: Copy + Eq + Hash + Debug;
+
+    /// Get the cell at a position, or `None` if the position is not part of the plane.
+    fn cell(&self, position: Self::Pos) -> Option<&Self::Cell>;
+
+    /// Replace the cell at a position. Positions that are not part of the plane are ignored.
+    fn set_cell(&mut self, position: Self::Pos, cell: Self::Cell);
+
+    /// Move a position by an offset, following the topology of the plane. Returns `None` if
+    /// the new position is not part of the plane.
+    fn step(&self, position: Self::Pos, offset: Offset) -> Option<Self::Pos>;
+
+    /// Check if repeatedly stepping from a position by an offset can only ever reach cells
+    /// that were never set, so walking on cannot find anything new. Always false for planes
+    /// without such a region, where walks end by leaving the plane or by looping.
+    fn is_beyond(&self, position: Self::Pos, offset: Offset) -> bool;
+
+    /// Find the first position of a cell equal to `cell`, in row major order.
+    fn find(&self, cell: &Self::Cell) -> Option<Self::Pos>
+    where
+        Self::Cell: PartialEq;
+
+    /// Find the positions of every cell matching a predicate, in row major order.
+    fn find_all_by<F>(&self, predicate: F) -> Vec<Self::Pos>
+    where
+        F: FnMut(&Self::Cell) -> bool;
+
+    /// Iterate over the positions of the orthogonal neighbours of a position, clockwise from
+    /// the neighbour above. Neighbours that are not part of the plane are skipped.
+    fn neighbours4(&self, position: Self::Pos) -> impl Iterator<Item = Self::Pos> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()))
+    }
+
+    /// Iterate over the positions of the orthogonal and diagonal neighbours of a position,
+    /// clockwise from the neighbour above. Neighbours that are not part of the plane are
+    /// skipped.
+    fn neighbours8(&self, position: Self::Pos) -> impl Iterator<Item = Self::Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()))
+    }
+}
@@ -0,0 +1,651 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+/// Slices of at most this length are sorted with insertion sort by quick sort and merge sort
+/// instead of being split further.
+pub const INSERTION_SORT_CUTOFF: usize = 16;
+
+/// Work done by a sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SortStats {
+    /// Number of calls to the comparison function.
+    pub comparisons: u64,
+    /// Number of swaps of two elements. The stable sorts order the indices of the elements
+    /// before moving the elements into place, so their swaps of indices are counted too.
+    /// Merge sort copies indices between buffers while merging, which is not counted.
+    pub swaps: u64,
+}
+
+impl Add for SortStats {
+    type Output = SortStats;
+
+    fn add(self, other: SortStats) -> SortStats {
+        SortStats {
+            comparisons: self.comparisons + other.comparisons,
+            swaps: self.swaps + other.swaps,
+        }
+    }
+}
+
+impl AddAssign for SortStats {
+    fn add_assign(&mut self, other: SortStats) {
+        *self = *self + other;
+    }
+}
+
+/// The sorting algorithms of this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortAlgorithm {
+    /// Quick sort with heap sort and insertion sort fallbacks. Not stable.
+    QuickSort,
+    /// Quick sort of the indices of the elements. Stable.
+    QuickSortStable,
+    /// Top down merge sort of the indices of the elements. Stable.
+    MergeSort,
+    /// Heap sort. Not stable.
+    HeapSort,
+    /// Insertion sort. Stable, but O(n^2).
+    InsertionSort,
+}
+
+impl SortAlgorithm {
+    /// Every algorithm.
+    pub const ALL: [SortAlgorithm; 5] = [
+        SortAlgorithm::QuickSort,
+        SortAlgorithm::QuickSortStable,
+        SortAlgorithm::MergeSort,
+        SortAlgorithm::HeapSort,
+        SortAlgorithm::InsertionSort,
+    ];
+
+    /// Check if the algorithm keeps equal elements in their original order.
+    pub fn is_stable(self) -> bool {
+        !matches!(self, SortAlgorithm::QuickSort | SortAlgorithm::HeapSort)
+    }
+
+    /// Sort a slice in place with the algorithm.
+    ///
+    /// # Arguments
+    ///    list: The slice to sort.
+    ///    compare: Returns the ordering of two elements of the slice.
+    pub fn sort<T, F>(self, list: &mut [T], mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.run(list, &mut compare, &mut 0);
+    }
+
+    /// Sort a slice in place with the algorithm, counting the comparisons and swaps made.
+    ///
+    /// # Arguments
+    ///    list: The slice to sort.
+    ///    compare: Returns the ordering of two elements of the slice.
+    pub fn sort_instrumented<T, F>(self, list: &mut [T], mut compare: F) -> SortStats
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut comparisons = 0;
+        let mut swaps = 0;
+        let mut counted_compare = |a: &T, b: &T| {
+            comparisons += 1;
+            compare(a, b)
+        };
+        self.run(list, &mut counted_compare, &mut swaps);
+
+        SortStats { comparisons, swaps }
+    }
+
+    /// Sort a slice with the algorithm, adding the swaps made to `swaps`.
+    fn run<T, F>(self, list: &mut [T], compare: &mut F, swaps: &mut u64)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        match self {
+            SortAlgorithm::QuickSort => introsort(list, compare, depth_limit(list.len()), swaps),
+            SortAlgorithm::QuickSortStable => stable_quick_sort(list, compare, swaps),
+            SortAlgorithm::MergeSort => stable_merge_sort(list, compare, swaps),
+            SortAlgorithm::HeapSort => heap_sort_counted(list, compare, swaps),
+            SortAlgorithm::InsertionSort => insertion_sort_counted(list, compare, swaps),
+        }
+    }
+}
+
+impl fmt::Display for SortAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SortAlgorithm::QuickSort => "quick sort",
+            SortAlgorithm::QuickSortStable => "stable quick sort",
+            SortAlgorithm::MergeSort => "merge sort",
+            SortAlgorithm::HeapSort => "heap sort",
+            SortAlgorithm::InsertionSort => "insertion sort",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Swap two elements of a slice and count the swap.
+fn swap<T>(list: &mut [T], a: usize, b: usize, swaps: &mut u64) {
+    list.swap(a, b);
+    *swaps += 1;
+}
+
+/// Sort a slice in place with insertion sort. Stable and fast on short or nearly sorted
+/// slices.
+fn insertion_sort_counted<T, F>(list: &mut [T], compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for idx in 1..list.len() {
+        let mut current = idx;
+        while current > 0 && compare(&list[current - 1], &list[current]).is_gt() {
+            swap(list, current - 1, current, swaps);
+            current -= 1;
+        }
+    }
+}
+
+/// Move the element at `root` down the max heap formed by the first `len` elements until
+/// both of its children are smaller.
+fn sift_down<T, F>(list: &mut [T], mut root: usize, len: usize, compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let mut largest = root;
+        for child in [2 * root + 1, 2 * root + 2] {
+            if child < len && compare(&list[child], &list[largest]).is_gt() {
+                largest = child;
+            }
+        }
+        if largest == root {
+            return;
+        }
+        swap(list, root, largest, swaps);
+        root = largest;
+    }
+}
+
+/// Sort a slice in place with heap sort, which is O(n log n) on every input.
+fn heap_sort_counted<T, F>(list: &mut [T], compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = list.len();
+    for root in (0..len / 2).rev() {
+        sift_down(list, root, len, compare, swaps);
+    }
+    for end in (1..len).rev() {
+        swap(list, 0, end, swaps);
+        sift_down(list, 0, end, compare, swaps);
+    }
+}
+
+/// Partition a slice of at least three elements around the median of its first, middle and
+/// last elements. Returns the final index of the pivot: every element before it is not
+/// greater than the pivot and every element after it is not less than the pivot.
+fn partition<T, F>(list: &mut [T], compare: &mut F, swaps: &mut u64) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = list.len();
+    let (mid, last) = (len / 2, len - 1);
+
+    // Order the first, middle and last elements, then use the middle one as the pivot.
+    if compare(&list[mid], &list[0]).is_lt() {
+        swap(list, mid, 0, swaps);
+    }
+    if compare(&list[last], &list[mid]).is_lt() {
+        swap(list, last, mid, swaps);
+        if compare(&list[mid], &list[0]).is_lt() {
+            swap(list, mid, 0, swaps);
+        }
+    }
+    swap(list, 0, mid, swaps);
+
+    // Both scans stop on elements equal to the pivot, so runs of equal elements are split
+    // evenly instead of all ending up on one side.
+    let mut left_idx = 0;
+    let mut right_idx = len;
+    loop {
+        left_idx += 1;
+        while left_idx < len && compare(&list[left_idx], &list[0]).is_lt() {
+            left_idx += 1;
+        }
+        right_idx -= 1;
+        while compare(&list[right_idx], &list[0]).is_gt() {
+            right_idx -= 1;
+        }
+        if left_idx >= right_idx {
+            break;
+        }
+        swap(list, left_idx, right_idx, swaps);
+    }
+
+    swap(list, 0, right_idx, swaps);
+    right_idx
+}
+
+/// Number of partitions quick sort may make before falling back to heap sort: twice the
+/// depth of a perfectly balanced sort.
+fn depth_limit(len: usize) -> usize {
+    2 * (usize::BITS - len.leading_zeros()) as usize
+}
+
+/// Sort a slice with quick sort, falling back to heap sort once `depth_limit` partitions
+/// have been made and to insertion sort for short slices.
+fn introsort<T, F>(mut list: &mut [T], compare: &mut F, mut depth_limit: usize, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        if list.len() <= INSERTION_SORT_CUTOFF {
+            insertion_sort_counted(list, compare, swaps);
+            return;
+        }
+        if depth_limit == 0 {
+            heap_sort_counted(list, compare, swaps);
+            return;
+        }
+        depth_limit -= 1;
+
+        let pivot_idx = partition(list, compare, swaps);
+        let (left, right) = list.split_at_mut(pivot_idx);
+        let right = &mut right[1..];
+
+        // Recurse into the smaller side and loop on the larger one, so the stack never holds
+        // more than O(log n) frames.
+        if left.len() < right.len() {
+            introsort(left, compare, depth_limit, swaps);
+            list = right;
+        } else {
+            introsort(right, compare, depth_limit, swaps);
+            list = left;
+        }
+    }
+}
+
+/// Move every element of a slice into place. Element `order[idx]` belongs at `idx`.
+fn apply_order<T>(list: &mut [T], order: &[usize], swaps: &mut u64) {
+    // Elements before `idx` are already in place, so if the element was swapped away from
+    // its original index, follow where it was moved to.
+    for idx in 0..list.len() {
+        let mut source = order[idx];
+        while source < idx {
+            source = order[source];
+        }
+        if source != idx {
+            swap(list, idx, source, swaps);
+        }
+    }
+}
+
+/// Sort a slice by quick sorting the indices of its elements, breaking ties by index.
+fn stable_quick_sort<T, F>(list: &mut [T], compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut order = (0..list.len()).collect::<Vec<_>>();
+    let mut compare_indices = |a: &usize, b: &usize| compare(&list[*a], &list[*b]).then(a.cmp(b));
+    introsort(&mut order, &mut compare_indices, depth_limit(list.len()), swaps);
+    apply_order(list, &order, swaps);
+}
+
+/// Merge sort a slice of indices, using `buffer` of the same length to merge into. Runs of
+/// equal elements keep their order.
+fn merge_sort_indices<F>(order: &mut [usize], buffer: &mut [usize], compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&usize, &usize) -> Ordering,
+{
+    let len = order.len();
+    if len <= INSERTION_SORT_CUTOFF {
+        insertion_sort_counted(order, compare, swaps);
+        return;
+    }
+
+    let mid = len / 2;
+    {
+        let (left, right) = order.split_at_mut(mid);
+        let (left_buffer, right_buffer) = buffer.split_at_mut(mid);
+        merge_sort_indices(left, left_buffer, compare, swaps);
+        merge_sort_indices(right, right_buffer, compare, swaps);
+    }
+
+    // The halves are already in order if the last element of the left one is not greater
+    // than the first element of the right one.
+    if !compare(&order[mid - 1], &order[mid]).is_gt() {
+        return;
+    }
+
+    // Take from the left half on ties, which keeps the merge stable.
+    let (mut left_idx, mut right_idx) = (0, mid);
+    for merged in buffer.iter_mut() {
+        let take_left = right_idx == len
+            || (left_idx < mid && !compare(&order[left_idx], &order[right_idx]).is_gt());
+        if take_left {
+            *merged = order[left_idx];
+            left_idx += 1;
+        } else {
+            *merged = order[right_idx];
+            right_idx += 1;
+        }
+    }
+    order.copy_from_slice(buffer);
+}
+
+/// Sort a slice by merge sorting the indices of its elements.
+fn stable_merge_sort<T, F>(list: &mut [T], compare: &mut F, swaps: &mut u64)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut order = (0..list.len()).collect::<Vec<_>>();
+    let mut buffer = vec![0; list.len()];
+    let mut compare_indices = |a: &usize, b: &usize| compare(&list[*a], &list[*b]);
+    merge_sort_indices(&mut order, &mut buffer, &mut compare_indices, swaps);
+    apply_order(list, &order, swaps);
+}
+
+/// Sort a slice in place with insertion sort. Stable, fast on short or nearly sorted slices
+/// and O(n^2) otherwise.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn insertion_sort<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortAlgorithm::InsertionSort.sort(list, compare);
+}
+
+/// Sort a slice in place with heap sort. Not stable, but O(n log n) on every input and needs
+/// no extra memory.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn heap_sort<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortAlgorithm::HeapSort.sort(list, compare);
+}
+
+/// Sort a slice in place with merge sort, keeping equal elements in their original order.
+/// Sorts the indices of the elements, switching to insertion sort for short runs, and then
+/// moves every element into place, so it needs O(n) extra memory but no copies of the
+/// elements.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn merge_sort<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortAlgorithm::MergeSort.sort(list, compare);
+}
+
+/// Sort a slice in place with quick sort. The sort is not stable: equal elements may be
+/// reordered. Slices that would make quick sort degrade to O(n^2) are finished with heap sort,
+/// so the sort is O(n log n) on every input.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn quick_sort_unstable<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortAlgorithm::QuickSort.sort(list, compare);
+}
+
+/// Sort a slice in place with quick sort, keeping equal elements in their original order.
+/// Sorts the indices of the elements and then moves every element into place, so it needs
+/// O(n) extra memory but no copies of the elements.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn quick_sort_stable<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    SortAlgorithm::QuickSortStable.sort(list, compare);
+}
+
+/// Sort a slice in place with quick sort. Same as [`quick_sort_unstable`].
+///
+/// # Arguments
+///    list: The slice to sort.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn quick_sort<T, F>(list: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_unstable(list, compare);
+}
+
+/// Sort a slice in place with quick sort, ordering the elements by a key. Same as
+/// [`quick_sort_unstable_by_key`].
+///
+/// # Arguments
+///    list: The slice to sort.
+///    key: Returns the key of an element.
+pub fn quick_sort_by_key<T, K, F>(list: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_unstable_by_key(list, key);
+}
+
+/// Sort a slice in place with quick sort, ordering the elements by a key. The sort is not
+/// stable.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    key: Returns the key of an element.
+pub fn quick_sort_unstable_by_key<T, K, F>(list: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_unstable(list, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sort a slice in place with quick sort, ordering the elements by a key and keeping
+/// elements with equal keys in their original order.
+///
+/// # Arguments
+///    list: The slice to sort.
+///    key: Returns the key of an element.
+pub fn quick_sort_stable_by_key<T, K, F>(list: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_stable(list, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Reorder a slice so that the element at index `n` is the one that would be there if the
+/// slice was sorted, with every element before it not greater and every element after it not
+/// less. Uses quickselect, which only partitions the side of the slice containing `n`, so it
+/// is O(n) on average instead of the O(n log n) of a full sort. Falls back to heap sort like
+/// quick sort does. Returns the element at index `n`.
+///
+/// # Panics
+/// Panics if `n` is not an index of the slice.
+///
+/// # Arguments
+///    list: The slice to reorder.
+///    n: The index of the element to select.
+///    compare: Returns the ordering of two elements of the slice.
+pub fn select_nth_by<T, F>(list: &mut [T], n: usize, mut compare: F) -> &mut T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(n < list.len(), "index {n} out of range for slice of length {}", list.len());
+
+    let mut remaining = &mut *list;
+    let mut nth = n;
+    let mut depth_limit = depth_limit(remaining.len());
+    let mut swaps = 0;
+    loop {
+        if remaining.len() <= INSERTION_SORT_CUTOFF {
+            insertion_sort_counted(remaining, &mut compare, &mut swaps);
+            break;
+        }
+        if depth_limit == 0 {
+            heap_sort_counted(remaining, &mut compare, &mut swaps);
+            break;
+        }
+        depth_limit -= 1;
+
+        let pivot_idx = partition(remaining, &mut compare, &mut swaps);
+        let (left, right) = remaining.split_at_mut(pivot_idx);
+        match nth.cmp(&pivot_idx) {
+            Ordering::Equal => break,
+            Ordering::Less => remaining = left,
+            Ordering::Greater => {
+                remaining = &mut right[1..];
+                nth -= pivot_idx + 1;
+            }
+        }
+    }
+
+    &mut list[n]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{distributions::Uniform, Rng};
+
+    /// Inputs of various shapes and lengths, including the ones that make a naive quick sort
+    /// degrade.
+    fn test_inputs() -> Vec<Vec<i64>> {
+        let mut rng = rand::thread_rng();
+        let mut inputs = vec![vec![], vec![1], vec![2, 1], vec![1, 2, 3]];
+        for len in [5, 16, 17, 100, 1000, 10000] {
+            let sorted = (0..len).collect::<Vec<i64>>();
+            inputs.push(sorted.clone());
+            inputs.push(sorted.iter().rev().copied().collect());
+            inputs.push(vec![7; len as usize]);
+            // Organ pipe: ascending then descending.
+            inputs.push((0..len).map(|value| value.min(len - value)).collect());
+            inputs.push((0..len).map(|_| rng.sample(Uniform::new(0, 100000))).collect());
+            inputs.push((0..len).map(|_| rng.sample(Uniform::new(0, 4))).collect());
+        }
+        inputs
+    }
+
+    /// Algorithms fast enough to test on a slice of this length.
+    fn algorithms(len: usize) -> impl Iterator<Item = SortAlgorithm> {
+        SortAlgorithm::ALL
+            .into_iter()
+            .filter(move |algorithm| *algorithm != SortAlgorithm::InsertionSort || len <= 1000)
+    }
+
+    #[test]
+    fn test_sorting() {
+        for input in test_inputs() {
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut list = input.clone();
+            quick_sort(&mut list, |a, b| a.cmp(b));
+            assert_eq!(list, expected, "quick_sort of {} elements", input.len());
+
+            let mut list = input.clone();
+            quick_sort_stable(&mut list, |a, b| a.cmp(b));
+            assert_eq!(list, expected, "quick_sort_stable of {} elements", input.len());
+
+            for algorithm in algorithms(input.len()) {
+                let mut list = input.clone();
+                algorithm.sort(&mut list, |a, b| a.cmp(b));
+                assert_eq!(list, expected, "{algorithm} of {} elements", input.len());
+            }
+
+            let mut list = input.clone();
+            quick_sort_by_key(&mut list, |value| -value);
+            expected.reverse();
+            assert_eq!(list, expected, "quick_sort_by_key of {} elements", input.len());
+        }
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 10, 17, 1000] {
+            // Pair every value with its original index, then sort by value only.
+            let input = (0..len)
+                .map(|idx| (rng.sample(Uniform::new(0, 10)), idx))
+                .collect::<Vec<(i64, usize)>>();
+            let mut expected = input.clone();
+            expected.sort_by_key(|(value, _)| *value);
+
+            let mut list = input.clone();
+            quick_sort_stable_by_key(&mut list, |(value, _)| *value);
+            assert_eq!(list, expected);
+
+            let stable = algorithms(len).filter(|algorithm| algorithm.is_stable());
+            for algorithm in stable {
+                let mut list = input.clone();
+                algorithm.sort(&mut list, |a, b| a.0.cmp(&b.0));
+                assert_eq!(list, expected, "{algorithm} of {len} elements");
+            }
+        }
+    }
+
+    #[test]
+    fn test_heap_sort_fallback() {
+        // With no depth allowed, the slice is sorted entirely by heap sort.
+        let mut list = (0..1000).rev().collect::<Vec<i64>>();
+        introsort(&mut list, &mut |a: &i64, b: &i64| a.cmp(b), 0, &mut 0);
+        assert_eq!(list, (0..1000).collect::<Vec<i64>>());
+    }
+
+    #[test]
+    fn test_select_nth_by() {
+        for input in test_inputs().into_iter().filter(|input| !input.is_empty()) {
+            let mut expected = input.clone();
+            expected.sort();
+            for n in [0, input.len() / 3, input.len() / 2, input.len() - 1] {
+                let mut list = input.clone();
+                assert_eq!(*select_nth_by(&mut list, n, |a, b| a.cmp(b)), expected[n]);
+                assert!(list[..n].iter().all(|value| *value <= expected[n]));
+                assert!(list[n + 1..].iter().all(|value| *value >= expected[n]));
+            }
+        }
+
+        let mut list = [1, 2, 3];
+        assert_eq!(*select_nth_by(&mut list, 0, |a, b| b.cmp(a)), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_by_out_of_range() {
+        select_nth_by(&mut [1, 2, 3], 3, |a, b| a.cmp(b));
+    }
+
+    #[test]
+    fn test_instrumented() {
+        // Reversing three elements with insertion sort compares and swaps every pair.
+        let mut list = [3, 2, 1];
+        let stats = SortAlgorithm::InsertionSort.sort_instrumented(&mut list, |a, b| a.cmp(b));
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(stats, SortStats { comparisons: 3, swaps: 3 });
+
+        // A sorted slice needs no swaps and one comparison per merge of two runs.
+        let mut list = (0..64).collect::<Vec<i64>>();
+        let stats = SortAlgorithm::MergeSort.sort_instrumented(&mut list, |a, b| a.cmp(b));
+        assert_eq!(stats.swaps, 0);
+        assert_eq!(stats.comparisons, 4 * 15 + 3);
+
+        for algorithm in SortAlgorithm::ALL {
+            let stats = algorithm.sort_instrumented(&mut [] as &mut [i64], |a, b| a.cmp(b));
+            assert_eq!(stats, SortStats::default());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::direction::Offset;
+use crate::grid::{Grid, Position};
+use crate::plane::Plane;
+use std::collections::HashMap;
+use std::ops::Add;
+
+/// Position on an unbounded plane. Rows and columns may be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    /// Create a new point.
+    ///
+    /// # Arguments
+    ///    row: The row of the point.
+    ///    col: The column of the point.
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Point::new(position.row as isize, position.col as isize)
+    }
+}
+
+impl Add<Offset> for Point {
+    type Output = Point;
+
+    fn add(self, offset: Offset) -> Point {
+        Point::new(self.row + offset.row, self.col + offset.col)
+    }
+}
+
+/// An unbounded plane of cells. Only the cells that were set are stored; every other point
+/// holds the default cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+    /// Smallest and largest row and column of any cell that was set.
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create an empty plane.
+    ///
+    /// # Arguments
+    ///    default: The cell at every point that was not set.
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Create a plane holding the cells of a grid, with the top left cell of the grid at
+    /// point (0, 0). The plane extends past the edges of the grid with the default cell.
+    ///
+    /// # Arguments
+    ///    grid: The cells to copy.
+    ///    default: The cell at every point outside the grid.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse = Self::new(default);
+        for (position, cell) in grid.iter() {
+            sparse.set(Point::from(position), cell.clone());
+        }
+        sparse
+    }
+
+    /// Get the cell at a point.
+    pub fn get(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Set the cell at a point.
+    pub fn set(&mut self, point: Point, cell: T) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.row.min(point.row), min.col.min(point.col)),
+                Point::new(max.row.max(point.row), max.col.max(point.col)),
+            ),
+        });
+        self.cells.insert(point, cell);
+    }
+
+    /// Smallest and largest row and column of any cell that was set, or `None` if no cell
+    /// was set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Iterate over the cells that were set and their points, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+}
+
+impl<T> Plane for SparseGrid<T> {
+    type Cell = T;
+    type Pos = Point;
+
+    fn cell(&self, point: Point) -> Option<&T> {
+        Some(self.get(point))
+    }
+
+    fn set_cell(&mut self, point: Point, cell: T) {
+        self.set(point, cell);
+    }
+
+    fn step(&self, point: Point, offset: Offset) -> Option<Point> {
+        Some(point + offset)
+    }
+
+    fn is_beyond(&self, point: Point, offset: Offset) -> bool {
+        let Some((min, max)) = self.bounds else {
+            return true;
+        };
+
+        // The walk never reaches a cell that was set if, on either axis, it starts outside the
+        // bounds and does not move towards them.
+        let moving_away = |value: isize, step: isize, min: isize, max: isize| {
+            (value < min && step <= 0) || (value > max && step >= 0)
+        };
+        moving_away(point.row, offset.row, min.row, max.row)
+            || moving_away(point.col, offset.col, min.col, max.col)
+    }
+
+    fn find(&self, cell: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|(_, other)| *other == cell)
+            .map(|(point, _)| point)
+            .min()
+    }
+
+    fn find_all_by<F>(&self, mut predicate: F) -> Vec<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut points = self
+            .iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+            .collect::<Vec<_>>();
+        points.sort();
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let grid = Grid::parse("#.\n.^").unwrap();
+        let mut sparse = SparseGrid::from_grid(&grid, '.');
+        assert_eq!(*sparse.get(Point::new(0, 0)), '#');
+        assert_eq!(*sparse.get(Point::new(-5, 7)), '.');
+        assert_eq!(sparse.find(&'^'), Some(Point::new(1, 1)));
+        let points = sparse.find_all_by(|cell| *cell != '.');
+        assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 1)]);
+        assert_eq!(sparse.bounds(), Some((Point::new(0, 0), Point::new(1, 1))));
+
+        sparse.set(Point::new(-2, 3), '#');
+        assert_eq!(sparse.bounds(), Some((Point::new(-2, 0), Point::new(1, 3))));
+        assert_eq!(sparse.neighbours8(Point::new(-10, -10)).count(), 8);
+    }
+
+    #[test]
+    fn test_is_beyond() {
+        let sparse = SparseGrid::from_grid(&Grid::parse("..\n..").unwrap(), '.');
+        assert!(!sparse.is_beyond(Point::new(0, 0), Offset::new(-1, 0)));
+        assert!(sparse.is_beyond(Point::new(-1, 0), Offset::new(-1, 0)));
+        assert!(!sparse.is_beyond(Point::new(-1, 0), Offset::new(1, 0)));
+        assert!(sparse.is_beyond(Point::new(0, 2), Offset::new(1, 0)));
+        assert!(SparseGrid::new('.').is_beyond(Point::new(0, 0), Offset::new(0, 1)));
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Nodes that must each precede the next one, with the last node preceding the first.
+/// Returned when nodes can not be put in an order that satisfies every rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N> {
+    /// The nodes of the cycle, in order.
+    pub nodes: Vec<N>,
+}
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            write!(f, "{node} -> ")?;
+        }
+        match self.nodes.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Sort nodes so that every node comes after all of the nodes that must precede it, using
+/// Kahn's algorithm. Only the rules between the given nodes are considered, so the nodes can
+/// be any subset of a larger rule set. Whenever several nodes have no unordered predecessors
+/// left, they are taken in the order they were given, so the result is deterministic, but
+/// nodes without a rule between them can still change order. Returns the cycle that prevents
+/// the nodes from being ordered if there is one.
+///
+/// `must_precede` is called for every pair of nodes, which is fine for the short lists of
+/// nodes in the puzzles.
+///
+/// # Arguments
+///    nodes: The distinct nodes to sort.
+///    must_precede: Returns true if the first node must come before the second one.
+pub fn topological_sort<N, F>(nodes: &[N], mut must_precede: F) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone,
+    F: FnMut(&N, &N) -> bool,
+{
+    let len = nodes.len();
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); len];
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); len];
+    for from in 0..len {
+        for to in 0..len {
+            if from != to && must_precede(&nodes[from], &nodes[to]) {
+                successors[from].push(to);
+                predecessors[to].push(from);
+            }
+        }
+    }
+
+    // Repeatedly take the first node that has no predecessors left.
+    let mut in_degree = predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut ready = (0..len).filter(|idx| in_degree[*idx] == 0).collect::<VecDeque<_>>();
+    let mut order = Vec::with_capacity(len);
+    while let Some(idx) = ready.pop_front() {
+        order.push(idx);
+        for successor in &successors[idx] {
+            in_degree[*successor] -= 1;
+            if in_degree[*successor] == 0 {
+                ready.push_back(*successor);
+            }
+        }
+    }
+
+    if order.len() < len {
+        let cycle = find_cycle(&predecessors, &in_degree);
+        return Err(Cycle {
+            nodes: cycle.into_iter().map(|idx| nodes[idx].clone()).collect(),
+        });
+    }
+
+    Ok(order.into_iter().map(|idx| nodes[idx].clone()).collect())
+}
+
+/// Find a cycle among the nodes Kahn's algorithm could not order. Each of them still has a
+/// predecessor that was not ordered, so walking back through those predecessors must
+/// eventually revisit a node.
+fn find_cycle(predecessors: &[Vec<usize>], in_degree: &[usize]) -> Vec<usize> {
+    let Some(start) = in_degree.iter().position(|degree| *degree > 0) else {
+        return Vec::new();
+    };
+
+    let mut path = vec![start];
+    let mut position_in_path = vec![None; in_degree.len()];
+    position_in_path[start] = Some(0);
+    loop {
+        let current = *path.last().expect("Path should not be empty");
+        let predecessor = predecessors[current]
+            .iter()
+            .copied()
+            .find(|predecessor| in_degree[*predecessor] > 0)
+            .expect("Unordered node should have an unordered predecessor");
+
+        if let Some(cycle_start) = position_in_path[predecessor] {
+            // The path was walked backwards, so reverse it to get the nodes in order.
+            let mut cycle = path.split_off(cycle_start);
+            cycle.reverse();
+            return cycle;
+        }
+        position_in_path[predecessor] = Some(path.len());
+        path.push(predecessor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topological_sort() {
+        let rules = [(1, 2), (2, 3), (1, 3), (4, 2), (5, 6)];
+        let must_precede = |a: &u32, b: &u32| rules.contains(&(*a, *b));
+
+        assert_eq!(topological_sort(&[3, 2, 1], must_precede), Ok(vec![1, 2, 3]));
+        assert_eq!(topological_sort(&[3, 4, 1, 2], must_precede), Ok(vec![4, 1, 2, 3]));
+
+        // Rules involving nodes that are not being sorted are ignored, and nodes that are ready
+        // together are taken in input order.
+        assert_eq!(topological_sort(&[6, 3, 2], must_precede), Ok(vec![6, 2, 3]));
+        assert_eq!(topological_sort(&[7, 6, 5], must_precede), Ok(vec![7, 5, 6]));
+
+        // Unrelated nodes can still change order: 1 waits for 3, which comes after 2.
+        let must_precede = |a: &u32, b: &u32| (*a, *b) == (3, 1);
+        assert_eq!(topological_sort(&[1, 2, 3], must_precede), Ok(vec![2, 3, 1]));
+        assert_eq!(topological_sort(&[] as &[u32], must_precede), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let rules = [(1, 2), (2, 3), (3, 1), (0, 1), (3, 4)];
+        let must_precede = |a: &u32, b: &u32| rules.contains(&(*a, *b));
+
+        let cycle = topological_sort(&[4, 3, 2, 1, 0], must_precede).unwrap_err();
+        assert_eq!(cycle.nodes.len(), 3);
+        for (idx, node) in cycle.nodes.iter().enumerate() {
+            let next = cycle.nodes[(idx + 1) % cycle.nodes.len()];
+            assert!(must_precede(node, &next));
+        }
+
+        // The cycle is broken if one of its nodes is not being sorted.
+        assert_eq!(topological_sort(&[3, 2, 0, 4], must_precede), Ok(vec![2, 0, 3, 4]));
+
+        let cycle = Cycle { nodes: vec![1, 2, 3] };
+        assert_eq!(cycle.to_string(), "1 -> 2 -> 3 -> 1");
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::{parse_token, quick_sort, AocError, Grid, Position};
+
+/// Solve a tiny puzzle using only the public API of the crate, the way a solution in another
+/// crate would.
+#[test]
+fn test_public_api() {
+    let input = "3 1 2";
+    let mut values = input
+        .split_whitespace()
+        .map(|token| parse_token::<u32>(input, 0, token))
+        .collect::<Result<Vec<_>, AocError>>()
+        .unwrap();
+    quick_sort(&mut values, |a, b| a.cmp(b));
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let grid = Grid::parse("#.\n.#").unwrap();
+    let walls = grid.find_all(&'#').collect::<Vec<_>>();
+    assert_eq!(walls, vec![Position::new(0, 0), Position::new(1, 1)]);
+
+    let err = parse_token::<u32>("1 x", 2, &"1 x"[2..]).unwrap_err();
+    assert_eq!(err.to_string(), "<input>:3:3: invalid value \"x\": invalid digit found in string");
+}